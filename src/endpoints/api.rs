@@ -1,10 +1,19 @@
+use crate::endpoints::auth::{Credentials, ServiceAccount, UrlSigner};
+use crate::endpoints::geocoding::Geocoding;
 use crate::endpoints::place_search::PlaceSearch;
+use crate::endpoints::v1::places::Places;
 use dotenv::dotenv;
+use reqwest::redirect::Policy;
 use reqwest::Client;
+use std::fmt;
 
 pub struct GooglePlacesAPI {
-    api_key: String,
+    api_key: Option<String>,
+    credentials: Credentials,
+    url_signer: Option<UrlSigner>,
     client: Client,
+    /// Stops at redirects, used to resolve photo references to their URL.
+    redirect_client: Client,
 }
 
 impl GooglePlacesAPI {
@@ -20,26 +29,108 @@ impl GooglePlacesAPI {
     pub fn new(key: &str) -> Self {
         dotenv().ok();
         Self {
-            api_key: String::from(
-                key,
-            ),
+            api_key: Some(String::from(key)),
+            credentials: Credentials::from(key),
+            url_signer: None,
             client: Client::new(),
+            redirect_client: no_redirect_client(),
         }
     }
 
     pub fn from_env() -> Self {
         dotenv().ok();
+        Self::new(
+            &std::env::var("GOOGLE_PLACES_API_KEY").expect("GOOGLE_PLACES_API_KEY must be set."),
+        )
+    }
+
+    /// Creates a new instance of `GooglePlacesAPI` authenticating the Places API (New)
+    /// with OAuth2 access tokens of a service account.
+    ///
+    /// The legacy endpoints returned by `place_search` and `geocoding` only accept API keys,
+    /// use `with_api_key` if they are needed too, otherwise both return `Err(MissingApiKey)`.
+    pub fn from_service_account(service_account: ServiceAccount) -> Self {
         Self {
-            api_key: String::from(
-                std::env::var("GOOGLE_PLACES_API_KEY").expect("GOOGLE_PLACES_API_KEY must be set."),
-            ),
+            api_key: None,
+            credentials: Credentials::from(service_account),
+            url_signer: None,
             client: Client::new(),
+            redirect_client: no_redirect_client(),
         }
     }
 
+    /// Sets the API key used by the legacy endpoints, keeping the Places API (New) credentials.
+    pub fn with_api_key(mut self, key: &str) -> Self {
+        self.api_key = Some(String::from(key));
+        self
+    }
+
+    /// Signs every request of the legacy endpoints with the given URL signing secret.
+    ///
+    /// Signing is required for keys whose quota is enforced with digital signatures.
+    pub fn with_url_signer(mut self, url_signer: UrlSigner) -> Self {
+        self.url_signer = Some(url_signer);
+        self
+    }
+
+    /// The API key of the legacy endpoints.
+    fn legacy_api_key(&self) -> Result<&str, MissingApiKey> {
+        self.api_key.as_deref().ok_or(MissingApiKey)
+    }
+
     /// Returns a new `PlaceSearch` instance with the API key and client.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MissingApiKey` if the API was created with `from_service_account`
+    /// without `with_api_key`.
+    pub fn place_search(&self) -> Result<PlaceSearch, MissingApiKey> {
+        let mut place_search = PlaceSearch::new(self.legacy_api_key()?, &self.client);
+        place_search.with_redirect_client(&self.redirect_client);
+        if let Some(url_signer) = &self.url_signer {
+            place_search.with_url_signer(url_signer.clone());
+        }
+        Ok(place_search)
+    }
 
-    pub fn place_search(&self) -> PlaceSearch {
-        PlaceSearch::new(self.api_key.as_str(), &self.client)
+    /// Returns a new `Geocoding` instance for the Geocoding API with the API key and client.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MissingApiKey` if the API was created with `from_service_account`
+    /// without `with_api_key`.
+    pub fn geocoding(&self) -> Result<Geocoding<'_>, MissingApiKey> {
+        let mut geocoding = Geocoding::new(self.legacy_api_key()?, &self.client);
+        if let Some(url_signer) = &self.url_signer {
+            geocoding.with_url_signer(url_signer.clone());
+        }
+        Ok(geocoding)
     }
+
+    /// Returns a new `Places` instance for the Places API (New) with the API key and client.
+    pub fn places(&self) -> Places<'_> {
+        Places::new(self.credentials.clone(), &self.client)
+    }
+}
+
+/// The legacy endpoints were requested from a `GooglePlacesAPI` that has no API key.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct MissingApiKey;
+
+impl fmt::Display for MissingApiKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "the legacy endpoints require an API key, set it with `with_api_key`"
+        )
+    }
+}
+
+impl std::error::Error for MissingApiKey {}
+
+fn no_redirect_client() -> Client {
+    Client::builder()
+        .redirect(Policy::none())
+        .build()
+        .expect("Cannot build the HTTP client.")
 }
@@ -0,0 +1,458 @@
+use base64::alphabet::URL_SAFE;
+use base64::engine::general_purpose::{GeneralPurpose, GeneralPurposeConfig};
+use base64::engine::DecodePaddingMode;
+use base64::Engine;
+use hmac::{Hmac, Mac};
+use jsonwebtoken::{encode, Algorithm, EncodingKey, Header};
+use reqwest::{Client, Request, RequestBuilder};
+use serde::{Deserialize, Serialize};
+use sha1::Sha1;
+use std::fmt;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tokio::sync::Mutex;
+
+const DEFAULT_TOKEN_URI: &str = "https://oauth2.googleapis.com/token";
+const DEFAULT_SCOPE: &str = "https://www.googleapis.com/auth/cloud-platform";
+const TOKEN_LIFETIME: Duration = Duration::from_secs(3600);
+/// Tokens are refreshed this long before they expire.
+const REFRESH_MARGIN: Duration = Duration::from_secs(300);
+
+/// How requests to the Places API (New) are authenticated.
+#[derive(Debug, Clone)]
+pub enum Credentials {
+    /// Sends the key in the `X-Goog-Api-Key` header.
+    ApiKey(String),
+    /// Sends an OAuth2 access token obtained for a service account in the `Authorization` header.
+    ServiceAccount(Arc<ServiceAccount>),
+}
+
+impl Credentials {
+    /// Adds the authentication headers to a request, fetching an access token if needed.
+    pub async fn authorize(&self, request: RequestBuilder) -> Result<RequestBuilder, AuthError> {
+        match self {
+            Credentials::ApiKey(api_key) => Ok(request.header("X-Goog-Api-Key", api_key.as_str())),
+            Credentials::ServiceAccount(service_account) => {
+                Ok(request.bearer_auth(service_account.access_token().await?))
+            }
+        }
+    }
+}
+
+impl From<&str> for Credentials {
+    fn from(api_key: &str) -> Self {
+        Credentials::ApiKey(String::from(api_key))
+    }
+}
+
+impl From<ServiceAccount> for Credentials {
+    fn from(service_account: ServiceAccount) -> Self {
+        Credentials::ServiceAccount(Arc::new(service_account))
+    }
+}
+
+/// The fields of a service-account JSON key used to obtain access tokens.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ServiceAccountKey {
+    pub client_email: String,
+    pub private_key: String,
+    pub private_key_id: Option<String>,
+    pub token_uri: Option<String>,
+}
+
+impl fmt::Debug for ServiceAccountKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ServiceAccountKey")
+            .field("client_email", &self.client_email)
+            .field("private_key_id", &self.private_key_id)
+            .field("token_uri", &self.token_uri)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Serialize)]
+struct Claims<'c> {
+    iss: &'c str,
+    scope: &'c str,
+    aud: &'c str,
+    iat: u64,
+    exp: u64,
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    expires_in: Option<u64>,
+}
+
+struct AccessToken {
+    value: String,
+    expires_at: Instant,
+}
+
+/// Obtains and caches OAuth2 access tokens for a service account.
+///
+/// A JWT is signed locally with the private key of the service account and exchanged
+/// at the token endpoint. The token is reused until shortly before it expires.
+pub struct ServiceAccount {
+    key: ServiceAccountKey,
+    encoding_key: EncodingKey,
+    token_uri: String,
+    scope: String,
+    client: Client,
+    token: Mutex<Option<AccessToken>>,
+}
+
+impl ServiceAccount {
+    /// Loads a service account from the JSON key downloaded from the Google Cloud console.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, AuthError> {
+        let json = std::fs::read_to_string(path).map_err(AuthError::Io)?;
+        Self::from_json(&json)
+    }
+
+    /// Loads a service account from the content of a JSON key.
+    pub fn from_json(json: &str) -> Result<Self, AuthError> {
+        let key: ServiceAccountKey =
+            serde_json::from_str(json).map_err(|err| AuthError::InvalidKey(err.to_string()))?;
+        Self::from_key(key)
+    }
+
+    /// Creates a service account from an already parsed key.
+    pub fn from_key(key: ServiceAccountKey) -> Result<Self, AuthError> {
+        let encoding_key =
+            EncodingKey::from_rsa_pem(key.private_key.as_bytes()).map_err(AuthError::Jwt)?;
+        let token_uri = key
+            .token_uri
+            .clone()
+            .unwrap_or_else(|| String::from(DEFAULT_TOKEN_URI));
+
+        Ok(Self {
+            key,
+            encoding_key,
+            token_uri,
+            scope: String::from(DEFAULT_SCOPE),
+            client: Client::new(),
+            token: Mutex::new(None),
+        })
+    }
+
+    /// Overrides the endpoint the signed JWT is exchanged at.
+    pub fn with_token_uri(mut self, token_uri: &str) -> Self {
+        self.token_uri = String::from(token_uri);
+        self
+    }
+
+    /// Overrides the OAuth2 scope requested for the access token.
+    pub fn with_scope(mut self, scope: &str) -> Self {
+        self.scope = String::from(scope);
+        self
+    }
+
+    /// Returns a valid access token, exchanging a new JWT when the cached token is
+    /// missing or about to expire.
+    pub async fn access_token(&self) -> Result<String, AuthError> {
+        let mut token = self.token.lock().await;
+
+        if let Some(token) = token.as_ref() {
+            if Instant::now() + REFRESH_MARGIN < token.expires_at {
+                return Ok(token.value.clone());
+            }
+        }
+
+        let fetched = self.fetch_token().await?;
+        let value = fetched.value.clone();
+        *token = Some(fetched);
+        Ok(value)
+    }
+
+    fn signed_jwt(&self) -> Result<String, AuthError> {
+        let iat = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let claims = Claims {
+            iss: &self.key.client_email,
+            scope: &self.scope,
+            aud: &self.token_uri,
+            iat,
+            exp: iat + TOKEN_LIFETIME.as_secs(),
+        };
+        let mut header = Header::new(Algorithm::RS256);
+        header.kid = self.key.private_key_id.clone();
+
+        encode(&header, &claims, &self.encoding_key).map_err(AuthError::Jwt)
+    }
+
+    async fn fetch_token(&self) -> Result<AccessToken, AuthError> {
+        let jwt = self.signed_jwt()?;
+        let params = [
+            ("grant_type", "urn:ietf:params:oauth:grant-type:jwt-bearer"),
+            ("assertion", jwt.as_str()),
+        ];
+
+        let requested_at = Instant::now();
+        let resp = self
+            .client
+            .post(&self.token_uri)
+            .form(&params)
+            .send()
+            .await
+            .map_err(AuthError::Request)?;
+
+        if !resp.status().is_success() {
+            let status = resp.status();
+            let body = resp.text().await.unwrap_or_default();
+            return Err(AuthError::TokenEndpoint(format!("{}: {}", status, body)));
+        }
+
+        let token = resp
+            .json::<TokenResponse>()
+            .await
+            .map_err(AuthError::Request)?;
+        let expires_in = token
+            .expires_in
+            .map(Duration::from_secs)
+            .unwrap_or(TOKEN_LIFETIME);
+
+        Ok(AccessToken {
+            value: token.access_token,
+            expires_at: requested_at + expires_in,
+        })
+    }
+}
+
+impl fmt::Debug for ServiceAccount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ServiceAccount")
+            .field("key", &self.key)
+            .field("token_uri", &self.token_uri)
+            .field("scope", &self.scope)
+            .finish_non_exhaustive()
+    }
+}
+
+/// URL-safe base64, accepting secrets with or without padding.
+const URL_SAFE_BASE64: GeneralPurpose = GeneralPurpose::new(
+    &URL_SAFE,
+    GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+/// Signs legacy web-service requests with a URL signing secret.
+///
+/// The `signature` parameter is the HMAC-SHA1 of the path and query of the request,
+/// keyed with the URL-safe base64 decoded secret.
+#[derive(Clone)]
+pub struct UrlSigner {
+    secret: Vec<u8>,
+}
+
+impl UrlSigner {
+    /// Creates a signer from the URL-safe base64 secret shown in the Google Cloud console.
+    pub fn new(secret: &str) -> Result<Self, AuthError> {
+        let secret = URL_SAFE_BASE64
+            .decode(secret.trim())
+            .map_err(|err| AuthError::InvalidSecret(err.to_string()))?;
+        Ok(Self { secret })
+    }
+
+    /// Computes the signature of a path and query, e.g. `/maps/api/place/details/json?key=...`.
+    pub fn signature(&self, path_and_query: &str) -> String {
+        let mut mac =
+            Hmac::<Sha1>::new_from_slice(&self.secret).expect("HMAC accepts keys of any length");
+        mac.update(path_and_query.as_bytes());
+        URL_SAFE_BASE64.encode(mac.finalize().into_bytes())
+    }
+
+    /// Appends the `signature` parameter to the URL of a built request.
+    pub fn sign_request(&self, request: &mut Request) {
+        let url = request.url_mut();
+        let path_and_query = match url.query() {
+            Some(query) => format!("{}?{}", url.path(), query),
+            None => url.path().to_string(),
+        };
+        // The URL-safe alphabet needs no escaping, the padding is kept as-is like in Google's examples.
+        let signature = self.signature(&path_and_query);
+        let query = match url.query() {
+            Some(query) => format!("{}&signature={}", query, signature),
+            None => format!("signature={}", signature),
+        };
+        url.set_query(Some(&query));
+    }
+}
+
+impl fmt::Debug for UrlSigner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UrlSigner").finish_non_exhaustive()
+    }
+}
+
+#[derive(Debug)]
+pub enum AuthError {
+    /// The key file could not be read.
+    Io(std::io::Error),
+    /// The key is not a valid service-account JSON key.
+    InvalidKey(String),
+    /// The JWT could not be signed with the private key.
+    Jwt(jsonwebtoken::errors::Error),
+    /// The token endpoint could not be reached or answered with an unexpected body.
+    Request(reqwest::Error),
+    /// The token endpoint rejected the JWT.
+    TokenEndpoint(String),
+    /// The URL signing secret is not valid URL-safe base64.
+    InvalidSecret(String),
+}
+
+impl fmt::Display for AuthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AuthError::Io(err) => write!(f, "cannot read service account key: {}", err),
+            AuthError::InvalidKey(err) => write!(f, "invalid service account key: {}", err),
+            AuthError::Jwt(err) => write!(f, "cannot sign JWT: {}", err),
+            AuthError::Request(err) => write!(f, "token request failed: {}", err),
+            AuthError::TokenEndpoint(err) => write!(f, "token endpoint error: {}", err),
+            AuthError::InvalidSecret(err) => write!(f, "invalid URL signing secret: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for AuthError {}
+
+#[cfg(test)]
+mod tests {
+    use super::{Credentials, ServiceAccount, ServiceAccountKey, UrlSigner};
+    use crate::endpoints::api::{GooglePlacesAPI, MissingApiKey};
+    use crate::tests::http_stub;
+    use reqwest::Client;
+    use rsa::pkcs8::{EncodePrivateKey, LineEnding};
+    use rsa::RsaPrivateKey;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, OnceLock};
+
+    /// A service account with a throwaway RSA key, generated once per test run.
+    fn service_account() -> ServiceAccount {
+        static PRIVATE_KEY: OnceLock<String> = OnceLock::new();
+        let private_key = PRIVATE_KEY.get_or_init(|| {
+            RsaPrivateKey::new(&mut rand::thread_rng(), 2048)
+                .unwrap()
+                .to_pkcs8_pem(LineEnding::LF)
+                .unwrap()
+                .to_string()
+        });
+
+        ServiceAccount::from_key(ServiceAccountKey {
+            client_email: String::from("test@test-project.iam.gserviceaccount.com"),
+            private_key: private_key.clone(),
+            private_key_id: Some(String::from("0123456789abcdef")),
+            token_uri: None,
+        })
+        .unwrap()
+    }
+
+    /// Starts a stand-in token endpoint answering every request with `expires_in`
+    /// and a token numbered after the request count.
+    async fn token_endpoint(
+        expires_in: u64,
+    ) -> (String, Arc<AtomicUsize>, Arc<std::sync::Mutex<String>>) {
+        let count = Arc::new(AtomicUsize::new(0));
+        let last_body = Arc::new(std::sync::Mutex::new(String::new()));
+        let (task_count, task_body) = (count.clone(), last_body.clone());
+
+        let url = http_stub::serve(move |n, request| {
+            task_count.store(n, Ordering::SeqCst);
+            *task_body.lock().unwrap() = request.body.clone();
+            let body = format!(
+                r#"{{"access_token":"token-{}","expires_in":{},"token_type":"Bearer"}}"#,
+                n, expires_in
+            );
+            vec![http_stub::response(
+                "200 OK",
+                &[("Content-Type", "application/json")],
+                body.as_bytes(),
+            )]
+        })
+        .await;
+
+        (format!("{}/token", url), count, last_body)
+    }
+
+    #[tokio::test]
+    async fn test_access_token_is_cached() {
+        let (uri, count, last_body) = token_endpoint(3600).await;
+        let service_account = service_account().with_token_uri(&uri);
+
+        assert_eq!(service_account.access_token().await.unwrap(), "token-1");
+        assert_eq!(service_account.access_token().await.unwrap(), "token-1");
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+
+        let body = last_body.lock().unwrap().clone();
+        assert!(body.contains("grant_type=urn%3Aietf%3Aparams%3Aoauth%3Agrant-type%3Ajwt-bearer"));
+        assert_eq!(
+            body.split("assertion=").nth(1).unwrap().split('.').count(),
+            3
+        );
+    }
+
+    #[tokio::test]
+    async fn test_access_token_is_refreshed_before_expiry() {
+        let (uri, count, _) = token_endpoint(60).await;
+        let service_account = service_account().with_token_uri(&uri);
+
+        assert_eq!(service_account.access_token().await.unwrap(), "token-1");
+        assert_eq!(service_account.access_token().await.unwrap(), "token-2");
+        assert_eq!(count.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_authorize_sets_bearer_header() {
+        let (uri, _, _) = token_endpoint(3600).await;
+        let credentials = Credentials::from(service_account().with_token_uri(&uri));
+
+        let request = credentials
+            .authorize(Client::new().get("http://localhost/"))
+            .await
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(
+            request.headers().get("Authorization").unwrap(),
+            "Bearer token-1"
+        );
+
+        let request = Credentials::from("key")
+            .authorize(Client::new().get("http://localhost/"))
+            .await
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(request.headers().get("X-Goog-Api-Key").unwrap(), "key");
+    }
+
+    #[test]
+    fn test_legacy_endpoints_require_api_key() {
+        let api = GooglePlacesAPI::from_service_account(service_account());
+        assert_eq!(api.place_search().err(), Some(MissingApiKey));
+        assert_eq!(api.geocoding().err(), Some(MissingApiKey));
+
+        let api = api.with_api_key("key");
+        assert!(api.place_search().is_ok());
+        assert!(api.geocoding().is_ok());
+    }
+
+    #[test]
+    fn test_url_signature() {
+        // Example from the Google Maps Platform digital signature documentation.
+        let signer = UrlSigner::new("vNIXE0xscrmjlyV-12Nj_BvUPaw=").unwrap();
+        let mut request = Client::new()
+            .get("https://maps.googleapis.com/maps/api/geocode/json")
+            .query(&[("address", "New York"), ("client", "clientID")])
+            .build()
+            .unwrap();
+
+        signer.sign_request(&mut request);
+        assert_eq!(
+            request.url().as_str(),
+            "https://maps.googleapis.com/maps/api/geocode/json?address=New+York&client=clientID&signature=chaRF2hTJKOScPr-RQCEhZbSzIE="
+        );
+    }
+}
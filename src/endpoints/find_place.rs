@@ -1,3 +1,4 @@
+use crate::endpoints::auth::UrlSigner;
 use crate::types::constants::{Language, LocationBias, PlaceSearchPlace, PlaceSearchPlaceFields};
 use crate::types::FindPlaceSearchResult;
 use reqwest::Client;
@@ -10,6 +11,7 @@ pub struct FindPlace<'a> {
     fields: Option<HashSet<PlaceSearchPlaceFields>>,
     location_bias: Option<LocationBias>,
     api_key: String,
+    url_signer: Option<UrlSigner>,
     client: &'a Client,
     result: FindPlaceSearchResult,
 }
@@ -33,11 +35,18 @@ impl<'a> FindPlace<'a> {
             location_bias: None,
             input_type: None,
             api_key: String::from(api_key),
+            url_signer: None,
             client,
             result: Default::default(),
         }
     }
 
+    /// Sign the request with a URL signing secret, adding the `signature` parameter.
+    pub fn with_url_signer(&mut self, url_signer: UrlSigner) -> &mut FindPlace<'a> {
+        self.url_signer = Some(url_signer);
+        self
+    }
+
     /**
     Assign the input string for a FindPlace call.
 
@@ -130,7 +139,11 @@ impl<'a> FindPlace<'a> {
                 let url = "https://maps.googleapis.com/maps/api/place/findplacefromtext/json";
                 let params = self.build_params();
 
-                let resp = self.client.get(url).query(&params).send().await.unwrap();
+                let mut request = self.client.get(url).query(&params).build().unwrap();
+                if let Some(url_signer) = &self.url_signer {
+                    url_signer.sign_request(&mut request);
+                }
+                let resp = self.client.execute(request).await.unwrap();
 
                 match resp.json::<FindPlaceSearchResult>().await {
                     Ok(query_result) => {
@@ -0,0 +1,228 @@
+use crate::endpoints::auth::UrlSigner;
+use crate::types::constants::place::Viewport;
+use crate::types::constants::{ComponentFilter, Language};
+use crate::types::geocoding::{GeocodedAddress, GeocodingResult};
+use isocountry::CountryCode;
+use reqwest::Client;
+
+pub struct Geocode<'a> {
+    address: Option<String>,
+    components: Option<Vec<ComponentFilter>>,
+    bounds: Option<Viewport>,
+    region: Option<CountryCode>,
+    language: Option<Language>,
+    api_key: String,
+    url_signer: Option<UrlSigner>,
+    client: &'a Client,
+    result: GeocodingResult,
+}
+
+impl<'a> Geocode<'a> {
+    /// Construct a new `Geocode` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key` - The Google Maps Platform API key.
+    /// * `client` - The reqwest client instance.
+    pub fn new(api_key: &str, client: &'a Client) -> Self {
+        Self {
+            address: None,
+            components: None,
+            bounds: None,
+            region: None,
+            language: None,
+            api_key: String::from(api_key),
+            url_signer: None,
+            client,
+            result: Default::default(),
+        }
+    }
+
+    /// Sign the request with a URL signing secret, adding the `signature` parameter.
+    pub fn with_url_signer(&mut self, url_signer: UrlSigner) -> &mut Geocode<'a> {
+        self.url_signer = Some(url_signer);
+        self
+    }
+
+    /// Set the address to geocode, e.g. a street address or a city name.
+    pub fn with_address(&mut self, address: &str) -> &mut Geocode<'a> {
+        self.address = Some(String::from(address));
+        self
+    }
+
+    /// Restrict the results to the given components, e.g. a country or postal code.
+    pub fn with_components(&mut self, components: Vec<ComponentFilter>) -> &mut Geocode<'a> {
+        self.components = Some(components);
+        self
+    }
+
+    /// Prefer results within the given bounding box.
+    pub fn with_bounds(&mut self, bounds: Viewport) -> &mut Geocode<'a> {
+        self.bounds = Some(bounds);
+        self
+    }
+
+    /// Prefer results in the given country.
+    pub fn with_region(&mut self, region: CountryCode) -> &mut Geocode<'a> {
+        self.region = Some(region);
+        self
+    }
+
+    /// Set the language in which the results are returned.
+    pub fn with_language(&mut self, language: Language) -> &mut Geocode<'a> {
+        self.language = Some(language);
+        self
+    }
+
+    fn build_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = vec![("key", self.api_key.clone())];
+
+        if let Some(address) = &self.address {
+            params.push(("address", address.clone()));
+        }
+
+        if let Some(components) = &self.components {
+            params.push((
+                "components",
+                components
+                    .iter()
+                    .map(|c| c.to_string())
+                    .collect::<Vec<_>>()
+                    .join("|"),
+            ));
+        }
+
+        if let Some(Viewport {
+            northeast: Some(northeast),
+            southwest: Some(southwest),
+        }) = &self.bounds
+        {
+            params.push((
+                "bounds",
+                format!("{}|{}", southwest.to_string(), northeast.to_string()),
+            ));
+        }
+
+        if let Some(region) = &self.region {
+            params.push(("region", region.alpha2().to_lowercase()));
+        }
+
+        if let Some(language) = &self.language {
+            params.push(("language", language.to_string()));
+        }
+
+        params
+    }
+
+    /// Execute the Geocode call in a non-blocking fashion.
+    ///
+    /// # Errors
+    ///
+    /// If the request fails or the response cannot be parsed, an error is printed and `None` is returned.
+    /// Errors reported by the API are stored in the `status` and `error_message` fields of the result.
+    ///
+    /// # Panics
+    ///
+    /// Panics if neither an address nor components were provided.
+    pub async fn execute(&mut self) -> Option<&mut Geocode<'a>> {
+        if self.address.is_none() && self.components.is_none() {
+            panic!("Provide address or components for Geocode query!");
+        }
+
+        let url = "https://maps.googleapis.com/maps/api/geocode/json";
+        let params = self.build_params();
+
+        let mut request = match self.client.get(url).query(&params).build() {
+            Ok(request) => request,
+            Err(err) => {
+                println!("Error building request: {:?}", err);
+                return None;
+            }
+        };
+        if let Some(url_signer) = &self.url_signer {
+            url_signer.sign_request(&mut request);
+        }
+
+        let resp = match self.client.execute(request).await {
+            Ok(resp) => resp,
+            Err(err) => {
+                println!("Error sending request: {:?}", err);
+                return None;
+            }
+        };
+
+        match resp.json::<GeocodingResult>().await {
+            Ok(query_result) => {
+                self.result = query_result;
+                Some(self)
+            }
+            Err(err) => {
+                println!("Error parsing response: {:?}", err);
+                None
+            }
+        }
+    }
+
+    /// Execute the Geocode call in a blocking fashion.
+    #[cfg(feature = "blocking")]
+    pub fn execute_blocking(&mut self) -> Option<&mut Geocode<'a>> {
+        tokio::runtime::Runtime::new()
+            .unwrap()
+            .block_on(self.execute())
+    }
+
+    /// Retrieve the result at the specified index.
+    pub fn at(&self, index: usize) -> Option<&GeocodedAddress> {
+        self.result.results.get(index)
+    }
+
+    /// Retrieve a cloned `GeocodingResult`.
+    pub fn get_result(&self) -> GeocodingResult {
+        self.result.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Geocode;
+    use crate::types::constants::place::{Location, Viewport};
+    use crate::types::constants::{ComponentFilter, Language};
+    use isocountry::CountryCode;
+    use reqwest::Client;
+
+    #[test]
+    fn test_build_params() {
+        let client = Client::new();
+        let mut geocode = Geocode::new("key", &client);
+        geocode
+            .with_address("Winnetka")
+            .with_components(vec![
+                ComponentFilter::AdministrativeArea(String::from("IL")),
+                ComponentFilter::Country(CountryCode::USA),
+            ])
+            .with_bounds(Viewport {
+                northeast: Some(Location::new(34.236144, -118.500938)),
+                southwest: Some(Location::new(34.172684, -118.604794)),
+            })
+            .with_region(CountryCode::USA)
+            .with_language(Language::En);
+
+        assert_eq!(
+            geocode.build_params(),
+            vec![
+                ("key", String::from("key")),
+                ("address", String::from("Winnetka")),
+                (
+                    "components",
+                    String::from("administrative_area:IL|country:US")
+                ),
+                (
+                    "bounds",
+                    String::from("34.172684,-118.604794|34.236144,-118.500938")
+                ),
+                ("region", String::from("us")),
+                ("language", String::from("en")),
+            ]
+        );
+    }
+}
@@ -0,0 +1,53 @@
+use crate::endpoints::auth::UrlSigner;
+use crate::endpoints::geocode::Geocode;
+use crate::endpoints::reverse_geocode::ReverseGeocode;
+use reqwest::Client;
+
+pub struct Geocoding<'a> {
+    api_key: String,
+    url_signer: Option<UrlSigner>,
+    client: &'a Client,
+}
+
+impl<'a> Geocoding<'a> {
+    /// Constructs a new `Geocoding` instance.
+    ///
+    /// ## DO NOT USE THIS ALONE, USE THE `GooglePlacesAPI` STRUCT.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key` - The Google Maps Platform API key.
+    /// * `client` - A reference to a `reqwest::Client` for executing HTTP requests.
+    pub fn new(api_key: &str, client: &'a Client) -> Self {
+        Self {
+            api_key: String::from(api_key),
+            url_signer: None,
+            client,
+        }
+    }
+
+    /// Sets the URL signer applied to every request built by this instance.
+    pub fn with_url_signer(&mut self, url_signer: UrlSigner) -> &mut Geocoding<'a> {
+        self.url_signer = Some(url_signer);
+        self
+    }
+
+    /// Returns a new `Geocode` instance that can be used to turn an address into coordinates.
+    pub fn geocode(&self) -> Geocode<'a> {
+        let mut geocode = Geocode::new(self.api_key.as_str(), self.client);
+        if let Some(url_signer) = &self.url_signer {
+            geocode.with_url_signer(url_signer.clone());
+        }
+        geocode
+    }
+
+    /// Returns a new `ReverseGeocode` instance that can be used to turn coordinates or a
+    /// place id into an address.
+    pub fn reverse_geocode(&self) -> ReverseGeocode<'a> {
+        let mut reverse_geocode = ReverseGeocode::new(self.api_key.as_str(), self.client);
+        if let Some(url_signer) = &self.url_signer {
+            reverse_geocode.with_url_signer(url_signer.clone());
+        }
+        reverse_geocode
+    }
+}
@@ -1,7 +1,13 @@
 pub mod api;
+pub mod auth;
 pub mod find_place;
+pub mod geocode;
+pub mod geocoding;
 pub mod nearby_search;
 pub mod place_details;
 pub mod place_search;
 pub mod text_search;
 pub mod place_photos;
+pub mod place_photo_archive;
+pub mod reverse_geocode;
+pub mod v1;
@@ -1,3 +1,4 @@
+use crate::endpoints::auth::UrlSigner;
 use crate::types::constants::place::Location;
 use crate::types::constants::{Language, PlaceSearchPlace, PlaceTypes};
 use crate::types::NearbySearchResult;
@@ -17,6 +18,7 @@ pub struct NearbySearch<'a> {
     rankby: Option<String>,
     place_type: Option<String>,
     api_key: String,
+    url_signer: Option<UrlSigner>,
     client: &'a Client,
     result: NearbySearchResult,
 }
@@ -45,11 +47,18 @@ impl<'a> NearbySearch<'a> {
             rankby: None,
             place_type: None,
             api_key: String::from(api_key),
+            url_signer: None,
             client,
             result: Default::default(),
         }
     }
 
+    /// Sign the request with a URL signing secret, adding the `signature` parameter.
+    pub fn with_url_signer(&mut self, url_signer: UrlSigner) -> &mut NearbySearch<'a> {
+        self.url_signer = Some(url_signer);
+        self
+    }
+
     /// Set the location for the nearby search.
     ///
     /// # Arguments
@@ -272,7 +281,11 @@ impl<'a> NearbySearch<'a> {
         let mut page_count = 0;
 
         while page_count < max_pages {
-            let resp = self.client.get(url).query(&params).send().await.unwrap();
+            let mut request = self.client.get(url).query(&params).build().unwrap();
+            if let Some(url_signer) = &self.url_signer {
+                url_signer.sign_request(&mut request);
+            }
+            let resp = self.client.execute(request).await.unwrap();
 
             match resp.json::<NearbySearchResult>().await {
                 Ok(query_result) => {
@@ -1,3 +1,4 @@
+use crate::endpoints::auth::UrlSigner;
 use crate::types::constants::{Language, PlaceDetailsPlaceFields, ReviewSort};
 use crate::types::PlaceDetailsResult;
 use isocountry::CountryCode;
@@ -12,6 +13,7 @@ pub struct PlaceDetails<'a> {
     review_sort: Option<ReviewSort>,
     session_token: Option<String>,
     api_key: String,
+    url_signer: Option<UrlSigner>,
     client: &'a Client,
     result: PlaceDetailsResult,
 }
@@ -27,11 +29,18 @@ impl<'a> PlaceDetails<'a> {
             session_token: None,
             region: None,
             api_key: String::from(api_key),
+            url_signer: None,
             client,
             result: Default::default(),
         }
     }
 
+    /// Sign the request with a URL signing secret, adding the `signature` parameter.
+    pub fn with_url_signer(&mut self, url_signer: UrlSigner) -> &mut PlaceDetails<'a> {
+        self.url_signer = Some(url_signer);
+        self
+    }
+
     /**
     Assign the place_id for a PlaceDetails call.
 
@@ -167,7 +176,11 @@ impl<'a> PlaceDetails<'a> {
                 let url = "https://maps.googleapis.com/maps/api/place/details/json";
                 let params = self.build_params();
 
-                let resp = self.client.get(url).query(&params).send().await.unwrap();
+                let mut request = self.client.get(url).query(&params).build().unwrap();
+                if let Some(url_signer) = &self.url_signer {
+                    url_signer.sign_request(&mut request);
+                }
+                let resp = self.client.execute(request).await.unwrap();
 
                 match resp.json::<PlaceDetailsResult>().await {
                     Ok(query_result) => {
@@ -0,0 +1,330 @@
+use crate::endpoints::auth::UrlSigner;
+use crate::endpoints::place_details::PlaceDetails;
+use crate::endpoints::place_photos::{PhotoDownload, PhotoError, PlacePhotos, PHOTO_ENDPOINT};
+use crate::types::constants::{PlaceDetailsPlace, PlaceDetailsPlaceFields};
+use crate::types::place_details::PlaceDetailsStatus;
+use crate::types::{HtmlAttribution, Photo, PhotoManifest, PhotoManifestEntry};
+use futures::StreamExt;
+use reqwest::Client;
+use std::collections::HashSet;
+use std::path::Path;
+
+/// The name of the manifest written next to the photos.
+pub const MANIFEST_FILE: &str = "manifest.json";
+
+pub struct PlacePhotoArchive<'a> {
+    place_id: Option<String>,
+    photos: Option<Vec<Photo>>,
+    maxwidth: Option<u16>,
+    maxheight: Option<u16>,
+    max_size: Option<u64>,
+    concurrency: usize,
+    api_key: String,
+    url_signer: Option<UrlSigner>,
+    photo_endpoint: String,
+    client: &'a Client,
+}
+
+impl<'a> PlacePhotoArchive<'a> {
+    /// Construct a new `PlacePhotoArchive` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key` - The Google Places API key.
+    /// * `client` - The reqwest client instance.
+    pub fn new(api_key: &str, client: &'a Client) -> Self {
+        Self {
+            place_id: None,
+            photos: None,
+            maxwidth: None,
+            maxheight: None,
+            max_size: None,
+            concurrency: 4,
+            api_key: String::from(api_key),
+            url_signer: None,
+            photo_endpoint: String::from(PHOTO_ENDPOINT),
+            client,
+        }
+    }
+
+    /// Sign the requests with a URL signing secret, adding the `signature` parameter.
+    pub fn with_url_signer(&mut self, url_signer: UrlSigner) -> &mut PlacePhotoArchive<'a> {
+        self.url_signer = Some(url_signer);
+        self
+    }
+
+    /// Archive the photos of an already fetched place.
+    pub fn with_place(&mut self, place: &PlaceDetailsPlace) -> &mut PlacePhotoArchive<'a> {
+        self.place_id = Some(place.id.clone());
+        self.photos = Some(place.photos.clone().unwrap_or_default());
+        self
+    }
+
+    /// Archive the photos of a place id, fetching them with a Place Details request.
+    pub fn with_place_id(&mut self, place_id: &str) -> &mut PlacePhotoArchive<'a> {
+        self.place_id = Some(String::from(place_id));
+        self.photos = None;
+        self
+    }
+
+    /// Assign the maximum width of the downloaded photos.
+    pub fn with_maxwidth(&mut self, maxwidth: u16) -> &mut PlacePhotoArchive<'a> {
+        self.maxwidth = Some(maxwidth);
+        self
+    }
+
+    /// Assign the maximum height of the downloaded photos.
+    pub fn with_maxheight(&mut self, maxheight: u16) -> &mut PlacePhotoArchive<'a> {
+        self.maxheight = Some(maxheight);
+        self
+    }
+
+    /// Abort when a photo is larger than `max_size` bytes.
+    pub fn with_max_size(&mut self, max_size: u64) -> &mut PlacePhotoArchive<'a> {
+        self.max_size = Some(max_size);
+        self
+    }
+
+    /// Set the number of photos downloaded at the same time, 4 by default.
+    pub fn with_concurrency(&mut self, concurrency: usize) -> &mut PlacePhotoArchive<'a> {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    async fn photos(&self, place_id: &str) -> Result<Vec<Photo>, PhotoError> {
+        if let Some(photos) = &self.photos {
+            return Ok(photos.clone());
+        }
+
+        let mut details = PlaceDetails::new(self.api_key.as_str(), self.client);
+        details.with_place_id(place_id).with_fields(HashSet::from([
+            PlaceDetailsPlaceFields::PlaceId,
+            PlaceDetailsPlaceFields::Photo,
+        ]));
+        if let Some(url_signer) = &self.url_signer {
+            details.with_url_signer(url_signer.clone());
+        }
+
+        let result = match details.execute().await {
+            Some(details) => details.get_details(),
+            None => return Err(PhotoError::Details(String::from("request failed"))),
+        };
+        if result.status != PlaceDetailsStatus::Ok {
+            return Err(PhotoError::Details(String::from(result.status.as_str())));
+        }
+        Ok(result.place.photos.unwrap_or_default())
+    }
+
+    async fn download_photo(
+        &self,
+        directory: &Path,
+        index: usize,
+        photo: &Photo,
+    ) -> Result<(String, PhotoDownload), PhotoError> {
+        let mut place_photos = PlacePhotos::new(self.api_key.as_str(), self.client);
+        place_photos
+            .with_photo(photo)
+            .with_endpoint(&self.photo_endpoint);
+        if let Some(maxwidth) = self.maxwidth {
+            place_photos.with_maxwidth(maxwidth);
+        }
+        if let Some(maxheight) = self.maxheight {
+            place_photos.with_maxheight(maxheight);
+        }
+        if let Some(max_size) = self.max_size {
+            place_photos.with_max_size(max_size);
+        }
+        if let Some(url_signer) = &self.url_signer {
+            place_photos.with_url_signer(url_signer.clone());
+        }
+
+        // The extension is only known from the format once the photo is downloaded.
+        let stem = format!("photo-{:03}", index + 1);
+        let download = place_photos.save_to(directory.join(&stem)).await?;
+        let extension = download.format.map_or("jpg", |format| format.extension());
+        let file = format!("{}.{}", stem, extension);
+        tokio::fs::rename(directory.join(&stem), directory.join(&file)).await?;
+        Ok((file, download))
+    }
+
+    async fn save_photo(
+        &self,
+        directory: &Path,
+        index: usize,
+        photo: &Photo,
+    ) -> PhotoManifestEntry {
+        let mut entry = PhotoManifestEntry {
+            photo_reference: photo.id.clone(),
+            width: photo.width,
+            height: photo.height,
+            html_attributions: photo
+                .html_attributions
+                .iter()
+                .map(|html| HtmlAttribution::parse(html))
+                .collect(),
+            ..Default::default()
+        };
+        match self.download_photo(directory, index, photo).await {
+            Ok((file, download)) => {
+                entry.file = Some(file);
+                entry.content_type = download.content_type;
+                entry.bytes = download.bytes_written;
+            }
+            Err(err) => entry.error = Some(err.to_string()),
+        }
+        entry
+    }
+
+    /// Download every photo of the place into `directory` and write a `manifest.json`
+    /// mapping each file to its photo reference, size and attributions.
+    ///
+    /// A photo that cannot be downloaded is listed in the manifest with its `error` and no
+    /// `file`, the other photos are still downloaded.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PhotoError` if the photos of a place id cannot be fetched, or if the
+    /// directory or the manifest cannot be written.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no place or neither a maximum width nor a maximum height was provided.
+    pub async fn save_to(&self, directory: impl AsRef<Path>) -> Result<PhotoManifest, PhotoError> {
+        let place_id = match &self.place_id {
+            Some(place_id) => place_id.clone(),
+            None => panic!("Provide place or place_id for PlacePhotoArchive!"),
+        };
+        if self.maxwidth.is_none() && self.maxheight.is_none() {
+            panic!("Provide maxwidth or maxheight for PlacePhotoArchive!");
+        }
+
+        let directory = directory.as_ref();
+        tokio::fs::create_dir_all(directory).await?;
+
+        let photos = self.photos(&place_id).await?;
+        let entries = futures::stream::iter(photos.iter().enumerate())
+            .map(|(index, photo)| self.save_photo(directory, index, photo))
+            .buffered(self.concurrency)
+            .collect::<Vec<_>>()
+            .await;
+
+        let manifest = PhotoManifest {
+            place_id,
+            photos: entries,
+        };
+        let json = serde_json::to_vec_pretty(&manifest).map_err(std::io::Error::from)?;
+        tokio::fs::write(directory.join(MANIFEST_FILE), json).await?;
+
+        Ok(manifest)
+    }
+
+    /// Download the photos in a blocking fashion, see `save_to`.
+    #[cfg(feature = "blocking")]
+    pub fn save_to_blocking(
+        &self,
+        directory: impl AsRef<Path>,
+    ) -> Result<PhotoManifest, PhotoError> {
+        tokio::runtime::Runtime::new()
+            .unwrap()
+            .block_on(self.save_to(directory))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PlacePhotoArchive, MANIFEST_FILE};
+    use crate::tests::http_stub;
+    use crate::types::constants::PlaceDetailsPlace;
+    use crate::types::{Photo, PhotoManifest};
+    use reqwest::Client;
+
+    const PNG: &[u8] = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR\0\0\0\x01\0\0\0\x01";
+
+    fn photo(reference: &str, html_attributions: Vec<String>) -> Photo {
+        Photo {
+            id: String::from(reference),
+            height: 3024,
+            width: 4032,
+            html_attributions,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_with_place_uses_known_photos() {
+        let client = Client::new();
+        let place = PlaceDetailsPlace {
+            id: String::from("ChIJN1t_tDeuEmsRUsoyG83frY4"),
+            photos: Some(vec![photo("reference", vec![])]),
+            ..Default::default()
+        };
+
+        let mut archive = PlacePhotoArchive::new("key", &client);
+        archive.with_place(&place).with_concurrency(0);
+
+        assert_eq!(archive.concurrency, 1);
+        let photos = archive.photos(&place.id).await.unwrap();
+        assert_eq!(photos.len(), 1);
+        assert_eq!(photos[0].id, "reference");
+    }
+
+    #[tokio::test]
+    async fn test_save_to_writes_photos_and_manifest() {
+        let url = http_stub::serve(|count, _| match count {
+            1 => vec![http_stub::response(
+                "200 OK",
+                &[("Content-Type", "image/png")],
+                PNG,
+            )],
+            _ => vec![http_stub::response(
+                "200 OK",
+                &[("Content-Type", "text/html")],
+                b"<html><body>Quota exceeded</body></html>",
+            )],
+        })
+        .await;
+        let place = PlaceDetailsPlace {
+            id: String::from("ChIJN1t_tDeuEmsRUsoyG83frY4"),
+            photos: Some(vec![
+                photo(
+                    "first",
+                    vec![String::from(
+                        r#"<a href="https://maps.google.com/maps/contrib/1">Jane</a>"#,
+                    )],
+                ),
+                photo("second", vec![]),
+            ]),
+            ..Default::default()
+        };
+        let directory = std::env::temp_dir().join(format!("photo-archive-{}", std::process::id()));
+
+        let client = Client::new();
+        let mut archive = PlacePhotoArchive::new("key", &client);
+        archive
+            .with_place(&place)
+            .with_maxwidth(400)
+            .with_concurrency(1);
+        archive.photo_endpoint = format!("{}/photo", url);
+        let manifest = archive.save_to(&directory).await.unwrap();
+
+        assert_eq!(std::fs::read(directory.join("photo-001.png")).unwrap(), PNG);
+        assert!(!directory.join("photo-002").exists());
+        let written: PhotoManifest =
+            serde_json::from_slice(&std::fs::read(directory.join(MANIFEST_FILE)).unwrap()).unwrap();
+        assert_eq!(written, manifest);
+
+        let first = &written.photos[0];
+        assert_eq!(first.file.as_deref(), Some("photo-001.png"));
+        assert_eq!(first.photo_reference, "first");
+        assert_eq!(first.content_type.as_deref(), Some("image/png"));
+        assert_eq!(first.bytes, PNG.len() as u64);
+        assert_eq!(first.html_attributions[0].text(), "Jane");
+        assert_eq!(first.error, None);
+
+        let second = &written.photos[1];
+        assert_eq!(second.file, None);
+        assert_eq!(second.photo_reference, "second");
+        assert!(second.error.as_deref().unwrap().contains("not an image"));
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
@@ -1,15 +1,30 @@
+use crate::endpoints::auth::UrlSigner;
 use crate::types::constants::{Language, ReviewSort};
-use crate::types::{Photo, PlaceDetailsResult};
+use crate::types::{ImageFormat, NotAnImage, Photo, PhotoData, PhotoUrl, PlaceDetailsResult};
 use isocountry::CountryCode;
-use reqwest::Client;
+use reqwest::{Client, Request, Response};
 use std::collections::HashSet;
+use std::fmt;
+use std::path::Path;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+pub(crate) const PHOTO_ENDPOINT: &str = "https://maps.googleapis.com/maps/api/place/photo";
+
 pub struct PlacePhotos<'a> {
     photo_reference: Option<String>,
     maxwidht: Option<u16>,
     maxheight: Option<u16>,
+    max_size: Option<u64>,
+    skip_http_redirect: bool,
+    html_attributions: Vec<String>,
     api_key: String,
+    url_signer: Option<UrlSigner>,
+    endpoint: String,
     client: &'a Client,
+    redirect_client: Option<&'a Client>,
     result: Vec<u8>,
+    content_type: Option<String>,
+    photo_url: Option<PhotoUrl>,
 }
 
 impl<'a> PlacePhotos<'a> {
@@ -18,12 +33,40 @@ impl<'a> PlacePhotos<'a> {
             photo_reference: None,
             maxwidht: None,
             maxheight: None,
+            max_size: None,
+            skip_http_redirect: false,
+            html_attributions: vec![],
             api_key: String::from(api_key),
+            url_signer: None,
+            endpoint: String::from(PHOTO_ENDPOINT),
             client,
+            redirect_client: None,
             result: Default::default(),
+            content_type: None,
+            photo_url: None,
         }
     }
 
+    /// Sign the request with a URL signing secret, adding the `signature` parameter.
+    pub fn with_url_signer(&mut self, url_signer: UrlSigner) -> &mut PlacePhotos<'a> {
+        self.url_signer = Some(url_signer);
+        self
+    }
+
+    /// Send the request to another endpoint, e.g. a local stand-in in tests.
+    pub(crate) fn with_endpoint(&mut self, endpoint: &str) -> &mut PlacePhotos<'a> {
+        self.endpoint = String::from(endpoint);
+        self
+    }
+
+    /// Set the client used by `with_skip_http_redirect`, it must not follow redirects.
+    ///
+    /// `GooglePlacesAPI::place_search` sets it already.
+    pub fn with_redirect_client(&mut self, redirect_client: &'a Client) -> &mut PlacePhotos<'a> {
+        self.redirect_client = Some(redirect_client);
+        self
+    }
+
     /// Assign the photo_reference for a PlacePhotos call.
     ///
     /// # Arguments
@@ -40,6 +83,13 @@ impl<'a> PlacePhotos<'a> {
         self
     }
 
+    /// Assign the photo_reference of a `Photo`, keeping its attributions for `get_photo_url`.
+    pub fn with_photo(&mut self, photo: &Photo) -> &mut PlacePhotos<'a> {
+        self.photo_reference = Some(photo.id.clone());
+        self.html_attributions = photo.html_attributions.clone();
+        self
+    }
+
     /// Assign the maximum width for the requested photo.
     ///
     /// # Arguments
@@ -72,6 +122,19 @@ impl<'a> PlacePhotos<'a> {
         self
     }
 
+    /// When `true`, `execute` does not follow the redirect to the image and only stores
+    /// its URL, see `get_photo_url`. Requires a client set with `with_redirect_client`.
+    pub fn with_skip_http_redirect(&mut self, skip_http_redirect: bool) -> &mut PlacePhotos<'a> {
+        self.skip_http_redirect = skip_http_redirect;
+        self
+    }
+
+    /// Abort `download_to` and `save_to` when the photo is larger than `max_size` bytes.
+    pub fn with_max_size(&mut self, max_size: u64) -> &mut PlacePhotos<'a> {
+        self.max_size = Some(max_size);
+        self
+    }
+
     pub fn build_params(&self) -> Vec<(&'static str, String)> {
         let mut params = vec![("key", self.api_key.clone())];
 
@@ -91,14 +154,26 @@ impl<'a> PlacePhotos<'a> {
     }
 
 
+    fn build_request(&self) -> Result<Request, reqwest::Error> {
+        let params = self.build_params();
+
+        let mut request = self.client.get(&self.endpoint).query(&params).build()?;
+        if let Some(url_signer) = &self.url_signer {
+            url_signer.sign_request(&mut request);
+        }
+        Ok(request)
+    }
+
     pub async fn execute(&mut self) -> Option<&mut PlacePhotos<'a>> {
         match self.photo_reference.clone() {
             Some(_) => {
-                let url = "https://maps.googleapis.com/maps/api/place/photo";
-                let params = self.build_params();
-
-                let resp = self.client.get(url).query(&params).send().await.unwrap();
+                let request = self.build_request().unwrap();
+                if self.skip_http_redirect {
+                    return self.resolve_redirect(request).await;
+                }
+                let resp = self.client.execute(request).await.unwrap();
 
+                self.content_type = content_type(&resp);
                 match resp.bytes().await {
                     Ok(query_result) => {
                         self.result = query_result.to_vec();
@@ -116,6 +191,39 @@ impl<'a> PlacePhotos<'a> {
         }
     }
 
+    async fn resolve_redirect(&mut self, request: Request) -> Option<&mut PlacePhotos<'a>> {
+        // The main client follows redirects, a dedicated one is needed to stop at the 302.
+        let client = match self.redirect_client {
+            Some(client) => client,
+            None => panic!("Provide redirect_client to skip the http redirect!"),
+        };
+
+        let resp = match client.execute(request).await {
+            Ok(resp) => resp,
+            Err(err) => {
+                println!("Error sending request: {:?}", err);
+                return None;
+            }
+        };
+
+        match redirect_location(&resp) {
+            Some(url) => {
+                self.photo_url = Some(PhotoUrl {
+                    url,
+                    html_attributions: self.html_attributions.clone(),
+                });
+                Some(self)
+            }
+            None => {
+                println!(
+                    "Error resolving photo url: unexpected status {}",
+                    resp.status()
+                );
+                None
+            }
+        }
+    }
+
     #[cfg(feature = "blocking")]
     pub fn execute_blocking(&mut self) -> Option<&mut PlacePhotos<'a>> {
         tokio::runtime::Runtime::new()
@@ -126,4 +234,343 @@ impl<'a> PlacePhotos<'a> {
     pub fn get_photo(&self) -> Vec<u8> {
         self.result.clone()
     }
+
+    /// Inspect the photo fetched by `execute`: its MIME type, sniffed format and dimensions.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NotAnImage` when the response is not an image, e.g. a quota error page.
+    pub fn get_photo_data(&self) -> Result<PhotoData, NotAnImage> {
+        PhotoData::new(self.result.clone(), self.content_type.clone())
+    }
+
+    /// Produce resized and re-encoded variants, e.g. thumbnails, of the photo fetched by
+    /// `execute`, so a single large download serves every size.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PhotoError` if the response is not an image or a variant cannot be produced.
+    #[cfg(feature = "image")]
+    pub fn get_variants(
+        &self,
+        variants: &[crate::types::PhotoVariant],
+    ) -> Result<Vec<PhotoData>, PhotoError> {
+        Ok(self.get_photo_data()?.to_variants(variants)?)
+    }
+
+    /// The URL of the photo, set by `execute` when `with_skip_http_redirect(true)` was used.
+    pub fn get_photo_url(&self) -> Option<PhotoUrl> {
+        self.photo_url.clone()
+    }
+
+    /// Take the photo fetched by `execute` without cloning it, leaving the result empty.
+    pub fn take_photo(&mut self) -> Vec<u8> {
+        std::mem::take(&mut self.result)
+    }
+
+    /// Stream the photo into `writer` chunk by chunk, without buffering the whole image.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PhotoError` if the request fails, the API answers with an error status,
+    /// the photo exceeds the size set with `with_max_size` or writing fails. The writer may
+    /// hold a partial image in that case.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no photo reference was provided.
+    pub async fn download_to<W>(&self, writer: &mut W) -> Result<PhotoDownload, PhotoError>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        if self.photo_reference.is_none() {
+            panic!("Provide photo_reference to download a place's photo!");
+        }
+
+        let resp = self.client.execute(self.build_request()?).await?;
+        stream_to(resp, writer, self.max_size).await
+    }
+
+    /// Stream the photo into the file at `path`, removing the partial file on failure.
+    ///
+    /// # Errors
+    ///
+    /// See `download_to`.
+    pub async fn save_to(&self, path: impl AsRef<Path>) -> Result<PhotoDownload, PhotoError> {
+        let path = path.as_ref();
+        let mut file = tokio::fs::File::create(path).await?;
+
+        match self.download_to(&mut file).await {
+            Ok(download) => Ok(download),
+            Err(err) => {
+                drop(file);
+                let _ = tokio::fs::remove_file(path).await;
+                Err(err)
+            }
+        }
+    }
+}
+
+/// Metadata of a photo streamed by `PlacePhotos::download_to` or `PlacePhotos::save_to`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PhotoDownload {
+    /// The `Content-Length` announced by the server, if any.
+    pub content_length: Option<u64>,
+    /// The `Content-Type` of the photo, e.g. `image/jpeg`.
+    pub content_type: Option<String>,
+    /// The format sniffed from the first bytes of the photo.
+    pub format: Option<ImageFormat>,
+    /// The number of bytes written.
+    pub bytes_written: u64,
+}
+
+#[derive(Debug)]
+pub enum PhotoError {
+    /// The request could not be sent or the body could not be read.
+    Request(reqwest::Error),
+    /// The API answered with a non-success status.
+    Status(reqwest::StatusCode),
+    /// The photo is larger than the configured maximum size.
+    TooLarge { max_size: u64 },
+    /// Writing the photo failed.
+    Io(std::io::Error),
+    /// The photos of the place could not be looked up.
+    Details(String),
+    /// The response is not an image, e.g. a quota error page.
+    NotAnImage(NotAnImage),
+    /// The photo could not be decoded or re-encoded.
+    #[cfg(feature = "image")]
+    Image(image::ImageError),
+}
+
+impl fmt::Display for PhotoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PhotoError::Request(err) => write!(f, "request error: {}", err),
+            PhotoError::Status(status) => write!(f, "unexpected status: {}", status),
+            PhotoError::TooLarge { max_size } => {
+                write!(f, "photo is larger than {} bytes", max_size)
+            }
+            PhotoError::Io(err) => write!(f, "io error: {}", err),
+            PhotoError::Details(err) => write!(f, "place details error: {}", err),
+            PhotoError::NotAnImage(err) => write!(f, "{}", err),
+            #[cfg(feature = "image")]
+            PhotoError::Image(err) => write!(f, "image error: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for PhotoError {}
+
+impl From<reqwest::Error> for PhotoError {
+    fn from(err: reqwest::Error) -> Self {
+        PhotoError::Request(err)
+    }
+}
+
+impl From<NotAnImage> for PhotoError {
+    fn from(err: NotAnImage) -> Self {
+        PhotoError::NotAnImage(err)
+    }
+}
+
+#[cfg(feature = "image")]
+impl From<image::ImageError> for PhotoError {
+    fn from(err: image::ImageError) -> Self {
+        PhotoError::Image(err)
+    }
+}
+
+impl From<std::io::Error> for PhotoError {
+    fn from(err: std::io::Error) -> Self {
+        PhotoError::Io(err)
+    }
+}
+
+fn redirect_location(resp: &Response) -> Option<String> {
+    if !resp.status().is_redirection() {
+        return None;
+    }
+    resp.headers()
+        .get(reqwest::header::LOCATION)
+        .and_then(|v| v.to_str().ok())
+        .map(String::from)
+}
+
+/// The number of bytes needed to sniff the format of an image.
+const SNIFF_LENGTH: usize = 18;
+
+fn content_type(resp: &Response) -> Option<String> {
+    resp.headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .map(String::from)
+}
+
+async fn stream_to<W>(
+    mut resp: Response,
+    writer: &mut W,
+    max_size: Option<u64>,
+) -> Result<PhotoDownload, PhotoError>
+where
+    W: AsyncWrite + Unpin,
+{
+    if !resp.status().is_success() {
+        return Err(PhotoError::Status(resp.status()));
+    }
+
+    let mut download = PhotoDownload {
+        content_length: resp.content_length(),
+        content_type: content_type(&resp),
+        format: None,
+        bytes_written: 0,
+    };
+
+    if let (Some(max_size), Some(content_length)) = (max_size, download.content_length) {
+        if content_length > max_size {
+            return Err(PhotoError::TooLarge { max_size });
+        }
+    }
+
+    // Nothing is written until the first bytes are known to be an image.
+    let mut head = Vec::with_capacity(SNIFF_LENGTH);
+    while let Some(chunk) = resp.chunk().await? {
+        download.bytes_written += chunk.len() as u64;
+        if let Some(max_size) = max_size {
+            if download.bytes_written > max_size {
+                return Err(PhotoError::TooLarge { max_size });
+            }
+        }
+
+        if download.format.is_some() {
+            writer.write_all(&chunk).await?;
+        } else {
+            head.extend_from_slice(&chunk);
+            if head.len() >= SNIFF_LENGTH {
+                download.format = Some(sniff(&head, &download)?);
+                writer.write_all(&head).await?;
+            }
+        }
+    }
+    if download.format.is_none() {
+        download.format = Some(sniff(&head, &download)?);
+        writer.write_all(&head).await?;
+    }
+    writer.flush().await?;
+
+    Ok(download)
+}
+
+fn sniff(head: &[u8], download: &PhotoDownload) -> Result<ImageFormat, NotAnImage> {
+    ImageFormat::sniff(head).ok_or_else(|| NotAnImage::new(head, download.content_type.clone()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{redirect_location, stream_to, PhotoError};
+    use crate::tests::http_stub;
+    use crate::types::ImageFormat;
+    use reqwest::redirect::Policy;
+    use reqwest::Client;
+
+    /// Serves a JPEG-typed response in chunks, either announcing its content length or
+    /// with a chunked transfer encoding.
+    async fn photo_endpoint(chunks: Vec<&'static [u8]>, content_length: bool) -> String {
+        let url = http_stub::serve(move |_, _| {
+            if content_length {
+                let length: usize = chunks.iter().map(|c| c.len()).sum();
+                let head = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: image/jpeg\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                    length
+                );
+                std::iter::once(head.into_bytes())
+                    .chain(chunks.iter().map(|chunk| chunk.to_vec()))
+                    .collect()
+            } else {
+                let head = "HTTP/1.1 200 OK\r\nContent-Type: image/jpeg\r\nTransfer-Encoding: chunked\r\nConnection: close\r\n\r\n";
+                std::iter::once(head.as_bytes().to_vec())
+                    .chain(chunks.iter().map(|chunk| {
+                        let mut encoded = format!("{:x}\r\n", chunk.len()).into_bytes();
+                        encoded.extend_from_slice(chunk);
+                        encoded.extend_from_slice(b"\r\n");
+                        encoded
+                    }))
+                    .chain(std::iter::once(b"0\r\n\r\n".to_vec()))
+                    .collect()
+            }
+        })
+        .await;
+
+        format!("{}/photo", url)
+    }
+
+    #[tokio::test]
+    async fn test_stream_to_writer() {
+        let url = photo_endpoint(vec![b"\xff\xd8\xff", b"\xe0rest"], true).await;
+        let resp = Client::new().get(url).send().await.unwrap();
+
+        let mut photo = Vec::new();
+        let download = stream_to(resp, &mut photo, None).await.unwrap();
+
+        assert_eq!(photo, b"\xff\xd8\xff\xe0rest");
+        assert_eq!(download.content_length, Some(8));
+        assert_eq!(download.content_type.as_deref(), Some("image/jpeg"));
+        assert_eq!(download.format, Some(ImageFormat::Jpeg));
+        assert_eq!(download.bytes_written, 8);
+    }
+
+    #[tokio::test]
+    async fn test_stream_to_rejects_error_page() {
+        let chunks: Vec<&'static [u8]> = vec![b"<html><body>", b"Quota exceeded</body></html>"];
+        let url = photo_endpoint(chunks, false).await;
+        let resp = Client::new().get(url).send().await.unwrap();
+
+        let mut photo = Vec::new();
+        let err = stream_to(resp, &mut photo, None).await.unwrap_err();
+
+        assert!(matches!(err, PhotoError::NotAnImage(_)));
+        assert!(photo.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_stream_to_rejects_announced_size() {
+        let url = photo_endpoint(vec![b"0123456789"], true).await;
+        let resp = Client::new().get(url).send().await.unwrap();
+
+        let mut photo = Vec::new();
+        let err = stream_to(resp, &mut photo, Some(4)).await.unwrap_err();
+
+        assert!(matches!(err, PhotoError::TooLarge { max_size: 4 }));
+        assert!(photo.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_stream_to_rejects_streamed_size() {
+        let url = photo_endpoint(vec![b"0123", b"4567", b"89"], false).await;
+        let resp = Client::new().get(url).send().await.unwrap();
+
+        let mut photo = Vec::new();
+        let err = stream_to(resp, &mut photo, Some(6)).await.unwrap_err();
+
+        assert!(matches!(err, PhotoError::TooLarge { max_size: 6 }));
+        assert!(photo.len() <= 6);
+    }
+
+    #[tokio::test]
+    async fn test_redirect_location() {
+        const LOCATION: &str = "https://lh3.googleusercontent.com/p/abc=s1600-w400";
+        let url = http_stub::serve(|_, _| {
+            vec![http_stub::response(
+                "302 Found",
+                &[("Location", LOCATION)],
+                b"",
+            )]
+        })
+        .await;
+
+        let client = Client::builder().redirect(Policy::none()).build().unwrap();
+        let resp = client.get(url).send().await.unwrap();
+
+        assert_eq!(redirect_location(&resp).as_deref(), Some(LOCATION));
+    }
 }
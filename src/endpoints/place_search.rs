@@ -1,14 +1,18 @@
+use crate::endpoints::auth::UrlSigner;
 use crate::endpoints::text_search::TextSearch;
 
+use crate::endpoints::find_place::FindPlace;
 use crate::endpoints::nearby_search::NearbySearch;
 use crate::endpoints::place_details::PlaceDetails;
-use reqwest::Client;
-use crate::endpoints::find_place::FindPlace;
+use crate::endpoints::place_photo_archive::PlacePhotoArchive;
 use crate::endpoints::place_photos::PlacePhotos;
+use reqwest::Client;
 
 pub struct PlaceSearch<'a> {
     api_key: String,
+    url_signer: Option<UrlSigner>,
     client: &'a Client,
+    redirect_client: Option<&'a Client>,
 }
 
 impl<'a> PlaceSearch<'a> {
@@ -27,10 +31,24 @@ impl<'a> PlaceSearch<'a> {
     pub fn new(api_key: &str, client: &'a Client) -> Self {
         Self {
             api_key: String::from(api_key),
+            url_signer: None,
             client,
+            redirect_client: None,
         }
     }
 
+    /// Sets the URL signer applied to every request built by this instance.
+    pub fn with_url_signer(&mut self, url_signer: UrlSigner) -> &mut PlaceSearch<'a> {
+        self.url_signer = Some(url_signer);
+        self
+    }
+
+    /// Sets the client, not following redirects, used to resolve photo references to their URL.
+    pub fn with_redirect_client(&mut self, redirect_client: &'a Client) -> &mut PlaceSearch<'a> {
+        self.redirect_client = Some(redirect_client);
+        self
+    }
+
     /// Returns a new `TextSearch` instance that can be used to execute a
     /// Text Search request.
     ///
@@ -38,7 +56,11 @@ impl<'a> PlaceSearch<'a> {
     ///
     /// A new instance of `TextSearch`.
     pub fn text_search(&mut self) -> TextSearch {
-        let text_search_object: TextSearch = TextSearch::new(self.api_key.as_str(), self.client);
+        let mut text_search_object: TextSearch =
+            TextSearch::new(self.api_key.as_str(), self.client);
+        if let Some(url_signer) = &self.url_signer {
+            text_search_object.with_url_signer(url_signer.clone());
+        }
         text_search_object
     }
 
@@ -49,8 +71,11 @@ impl<'a> PlaceSearch<'a> {
     ///
     /// A new instance of `NearbySearch`.
     pub fn nearby_search(&mut self) -> NearbySearch {
-        let nearby_search_object: NearbySearch =
+        let mut nearby_search_object: NearbySearch =
             NearbySearch::new(self.api_key.as_str(), self.client);
+        if let Some(url_signer) = &self.url_signer {
+            nearby_search_object.with_url_signer(url_signer.clone());
+        }
         nearby_search_object
     }
 
@@ -61,7 +86,11 @@ impl<'a> PlaceSearch<'a> {
     ///
     /// A new instance of `PlaceDetails`.
     pub fn place_details(&mut self) -> PlaceDetails {
-        let details_object: PlaceDetails = PlaceDetails::new(self.api_key.as_str(), self.client);
+        let mut details_object: PlaceDetails =
+            PlaceDetails::new(self.api_key.as_str(), self.client);
+        if let Some(url_signer) = &self.url_signer {
+            details_object.with_url_signer(url_signer.clone());
+        }
         details_object
     }
 
@@ -72,12 +101,32 @@ impl<'a> PlaceSearch<'a> {
     ///
     /// A new instance of `FindPlace`.
     pub fn find_place(&mut self) -> FindPlace {
-        let find_place_object: FindPlace = FindPlace::new(self.api_key.as_str(), self.client);
+        let mut find_place_object: FindPlace = FindPlace::new(self.api_key.as_str(), self.client);
+        if let Some(url_signer) = &self.url_signer {
+            find_place_object.with_url_signer(url_signer.clone());
+        }
         find_place_object
     }
 
     pub fn place_photos(&mut self) -> PlacePhotos {
-        let place_photos_object: PlacePhotos = PlacePhotos::new(self.api_key.as_str(), self.client);
+        let mut place_photos_object: PlacePhotos =
+            PlacePhotos::new(self.api_key.as_str(), self.client);
+        if let Some(url_signer) = &self.url_signer {
+            place_photos_object.with_url_signer(url_signer.clone());
+        }
+        if let Some(redirect_client) = self.redirect_client {
+            place_photos_object.with_redirect_client(redirect_client);
+        }
         place_photos_object
     }
+
+    /// Returns a new `PlacePhotoArchive` instance that can be used to download every
+    /// photo of a place along with an attribution manifest.
+    pub fn place_photo_archive(&mut self) -> PlacePhotoArchive<'a> {
+        let mut archive = PlacePhotoArchive::new(self.api_key.as_str(), self.client);
+        if let Some(url_signer) = &self.url_signer {
+            archive.with_url_signer(url_signer.clone());
+        }
+        archive
+    }
 }
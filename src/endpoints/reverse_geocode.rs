@@ -0,0 +1,215 @@
+use crate::endpoints::auth::UrlSigner;
+use crate::types::constants::place::Location;
+use crate::types::constants::{Language, LocationType, PlaceTypes};
+use crate::types::geocoding::{GeocodedAddress, GeocodingResult};
+use reqwest::Client;
+
+pub struct ReverseGeocode<'a> {
+    location: Option<Location>,
+    place_id: Option<String>,
+    result_types: Option<Vec<PlaceTypes>>,
+    location_types: Option<Vec<LocationType>>,
+    language: Option<Language>,
+    api_key: String,
+    url_signer: Option<UrlSigner>,
+    client: &'a Client,
+    result: GeocodingResult,
+}
+
+impl<'a> ReverseGeocode<'a> {
+    /// Construct a new `ReverseGeocode` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key` - The Google Maps Platform API key.
+    /// * `client` - The reqwest client instance.
+    pub fn new(api_key: &str, client: &'a Client) -> Self {
+        Self {
+            location: None,
+            place_id: None,
+            result_types: None,
+            location_types: None,
+            language: None,
+            api_key: String::from(api_key),
+            url_signer: None,
+            client,
+            result: Default::default(),
+        }
+    }
+
+    /// Sign the request with a URL signing secret, adding the `signature` parameter.
+    pub fn with_url_signer(&mut self, url_signer: UrlSigner) -> &mut ReverseGeocode<'a> {
+        self.url_signer = Some(url_signer);
+        self
+    }
+
+    /// Set the coordinates to look up. Cannot be combined with a place id.
+    pub fn with_location(&mut self, location: Location) -> &mut ReverseGeocode<'a> {
+        self.location = Some(location);
+        self
+    }
+
+    /// Set the place id to look up. Cannot be combined with a location.
+    pub fn with_place_id(&mut self, place_id: &str) -> &mut ReverseGeocode<'a> {
+        self.place_id = Some(String::from(place_id));
+        self
+    }
+
+    /// Only return results of the given types, e.g. `PlaceTypes::StreetAddress`.
+    pub fn with_result_types(&mut self, result_types: Vec<PlaceTypes>) -> &mut ReverseGeocode<'a> {
+        self.result_types = Some(result_types);
+        self
+    }
+
+    /// Only return results with the given location precisions.
+    pub fn with_location_types(
+        &mut self,
+        location_types: Vec<LocationType>,
+    ) -> &mut ReverseGeocode<'a> {
+        self.location_types = Some(location_types);
+        self
+    }
+
+    /// Set the language in which the results are returned.
+    pub fn with_language(&mut self, language: Language) -> &mut ReverseGeocode<'a> {
+        self.language = Some(language);
+        self
+    }
+
+    fn build_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = vec![("key", self.api_key.clone())];
+
+        if let Some(location) = &self.location {
+            params.push(("latlng", location.to_string()));
+        }
+
+        if let Some(place_id) = &self.place_id {
+            params.push(("place_id", place_id.clone()));
+        }
+
+        if let Some(result_types) = &self.result_types {
+            params.push((
+                "result_type",
+                result_types
+                    .iter()
+                    .map(|t| t.to_string())
+                    .collect::<Vec<_>>()
+                    .join("|"),
+            ));
+        }
+
+        if let Some(location_types) = &self.location_types {
+            params.push((
+                "location_type",
+                location_types
+                    .iter()
+                    .map(|t| t.to_string())
+                    .collect::<Vec<_>>()
+                    .join("|"),
+            ));
+        }
+
+        if let Some(language) = &self.language {
+            params.push(("language", language.to_string()));
+        }
+
+        params
+    }
+
+    /// Execute the ReverseGeocode call in a non-blocking fashion.
+    ///
+    /// # Errors
+    ///
+    /// If the request fails or the response cannot be parsed, an error is printed and `None` is returned.
+    /// Errors reported by the API are stored in the `status` and `error_message` fields of the result.
+    ///
+    /// # Panics
+    ///
+    /// Panics unless exactly one of a location or a place id was provided.
+    pub async fn execute(&mut self) -> Option<&mut ReverseGeocode<'a>> {
+        if self.location.is_some() == self.place_id.is_some() {
+            panic!("Provide either location or place_id for ReverseGeocode query!");
+        }
+
+        let url = "https://maps.googleapis.com/maps/api/geocode/json";
+        let params = self.build_params();
+
+        let mut request = match self.client.get(url).query(&params).build() {
+            Ok(request) => request,
+            Err(err) => {
+                println!("Error building request: {:?}", err);
+                return None;
+            }
+        };
+        if let Some(url_signer) = &self.url_signer {
+            url_signer.sign_request(&mut request);
+        }
+
+        let resp = match self.client.execute(request).await {
+            Ok(resp) => resp,
+            Err(err) => {
+                println!("Error sending request: {:?}", err);
+                return None;
+            }
+        };
+
+        match resp.json::<GeocodingResult>().await {
+            Ok(query_result) => {
+                self.result = query_result;
+                Some(self)
+            }
+            Err(err) => {
+                println!("Error parsing response: {:?}", err);
+                None
+            }
+        }
+    }
+
+    /// Execute the ReverseGeocode call in a blocking fashion.
+    #[cfg(feature = "blocking")]
+    pub fn execute_blocking(&mut self) -> Option<&mut ReverseGeocode<'a>> {
+        tokio::runtime::Runtime::new()
+            .unwrap()
+            .block_on(self.execute())
+    }
+
+    /// Retrieve the result at the specified index.
+    pub fn at(&self, index: usize) -> Option<&GeocodedAddress> {
+        self.result.results.get(index)
+    }
+
+    /// Retrieve a cloned `GeocodingResult`.
+    pub fn get_result(&self) -> GeocodingResult {
+        self.result.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ReverseGeocode;
+    use crate::types::constants::place::Location;
+    use crate::types::constants::{Language, LocationType, PlaceTypes};
+    use reqwest::Client;
+
+    #[test]
+    fn test_build_params() {
+        let client = Client::new();
+        let mut reverse_geocode = ReverseGeocode::new("key", &client);
+        reverse_geocode
+            .with_location(Location::new(40.714224, -73.961452))
+            .with_result_types(vec![PlaceTypes::StreetAddress, PlaceTypes::PostalCode])
+            .with_location_types(vec![LocationType::Rooftop])
+            .with_language(Language::En);
+
+        assert_eq!(
+            reverse_geocode.build_params(),
+            vec![
+                ("key", String::from("key")),
+                ("latlng", String::from("40.714224,-73.961452")),
+                ("result_type", String::from("street_address|postal_code")),
+                ("location_type", String::from("ROOFTOP")),
+                ("language", String::from("en")),
+            ]
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use crate::endpoints::auth::UrlSigner;
 use crate::types::constants::place::Location;
 use crate::types::constants::{Language, PlaceSearchPlace, PlaceTypes};
 use crate::types::TextSearchResult;
@@ -17,6 +18,7 @@ pub struct TextSearch<'a> {
     region: Option<String>,
     place_type: Option<String>,
     api_key: String,
+    url_signer: Option<UrlSigner>,
     client: &'a Client,
     result: TextSearchResult,
 }
@@ -35,11 +37,18 @@ impl<'a> TextSearch<'a> {
             region: None,
             place_type: None,
             api_key: String::from(api_key),
+            url_signer: None,
             client: client,
             result: Default::default(),
         }
     }
 
+    /// Sign the request with a URL signing secret, adding the `signature` parameter.
+    pub fn with_url_signer(&mut self, url_signer: UrlSigner) -> &mut TextSearch<'a> {
+        self.url_signer = Some(url_signer);
+        self
+    }
+
     /**
     Assign the query string for a TextSearch call.
 
@@ -212,7 +221,11 @@ impl<'a> TextSearch<'a> {
                 let mut page_count = 0;
 
                 while page_count < max_pages {
-                    let resp = self.client.get(url).query(&params).send().await.unwrap();
+                    let mut request = self.client.get(url).query(&params).build().unwrap();
+                    if let Some(url_signer) = &self.url_signer {
+                        url_signer.sign_request(&mut request);
+                    }
+                    let resp = self.client.execute(request).await.unwrap();
 
                     match resp.json::<TextSearchResult>().await {
                         Ok(query_result) => {
@@ -0,0 +1,286 @@
+use crate::endpoints::auth::Credentials;
+use crate::types::constants::{Language, PlaceTypes};
+use crate::types::v1::{AutocompleteResult, LatLng, LocationArea};
+use isocountry::CountryCode;
+use reqwest::Client;
+use serde_json::{json, Map, Value};
+
+pub struct Autocomplete<'a> {
+    input: Option<String>,
+    input_offset: Option<u32>,
+    included_primary_types: Option<Vec<PlaceTypes>>,
+    included_region_codes: Option<Vec<CountryCode>>,
+    location_bias: Option<LocationArea>,
+    location_restriction: Option<LocationArea>,
+    origin: Option<LatLng>,
+    include_query_predictions: Option<bool>,
+    language: Option<Language>,
+    region: Option<CountryCode>,
+    session_token: Option<String>,
+    credentials: Credentials,
+    client: &'a Client,
+    result: AutocompleteResult,
+}
+
+impl<'a> Autocomplete<'a> {
+    /// Construct a new `Autocomplete` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - The credentials used to authenticate the request.
+    /// * `client` - A `reqwest::Client` instance.
+    pub fn new(credentials: Credentials, client: &'a Client) -> Self {
+        Self {
+            input: None,
+            input_offset: None,
+            included_primary_types: None,
+            included_region_codes: None,
+            location_bias: None,
+            location_restriction: None,
+            origin: None,
+            include_query_predictions: None,
+            language: None,
+            region: None,
+            session_token: None,
+            credentials,
+            client,
+            result: Default::default(),
+        }
+    }
+
+    /// Set the text typed by the user.
+    pub fn with_input(&mut self, input: &str) -> &mut Autocomplete<'a> {
+        self.input = Some(String::from(input));
+        self
+    }
+
+    /// Set the position of the cursor in the input, in unicode characters.
+    pub fn with_input_offset(&mut self, input_offset: u32) -> &mut Autocomplete<'a> {
+        self.input_offset = Some(input_offset);
+        self
+    }
+
+    /// Only return places whose primary type is one of the given types, up to 5.
+    pub fn with_included_primary_types(
+        &mut self,
+        included_primary_types: Vec<PlaceTypes>,
+    ) -> &mut Autocomplete<'a> {
+        self.included_primary_types = Some(included_primary_types);
+        self
+    }
+
+    /// Only return places in the given countries, up to 15.
+    pub fn with_included_region_codes(
+        &mut self,
+        included_region_codes: Vec<CountryCode>,
+    ) -> &mut Autocomplete<'a> {
+        self.included_region_codes = Some(included_region_codes);
+        self
+    }
+
+    /// Prefer results in the given area. Cannot be combined with a location restriction.
+    pub fn with_location_bias(&mut self, location_bias: LocationArea) -> &mut Autocomplete<'a> {
+        self.location_bias = Some(location_bias);
+        self
+    }
+
+    /// Only return results in the given area.
+    pub fn with_location_restriction(
+        &mut self,
+        location_restriction: LocationArea,
+    ) -> &mut Autocomplete<'a> {
+        self.location_restriction = Some(location_restriction);
+        self
+    }
+
+    /// Set the origin from which `distance_meters` of the place predictions is computed.
+    pub fn with_origin(&mut self, origin: LatLng) -> &mut Autocomplete<'a> {
+        self.origin = Some(origin);
+        self
+    }
+
+    /// Whether query predictions are returned along with place predictions.
+    pub fn with_include_query_predictions(
+        &mut self,
+        include_query_predictions: bool,
+    ) -> &mut Autocomplete<'a> {
+        self.include_query_predictions = Some(include_query_predictions);
+        self
+    }
+
+    /// Set the language in which the predictions are returned.
+    pub fn with_language(&mut self, language: Language) -> &mut Autocomplete<'a> {
+        self.language = Some(language);
+        self
+    }
+
+    /// Set the region used to format the response.
+    pub fn with_region(&mut self, region: CountryCode) -> &mut Autocomplete<'a> {
+        self.region = Some(region);
+        self
+    }
+
+    /// Set the session token grouping the autocomplete calls and the
+    /// concluding `GetPlace` call into one billing session.
+    pub fn with_session_token(&mut self, session_token: &str) -> &mut Autocomplete<'a> {
+        self.session_token = Some(String::from(session_token));
+        self
+    }
+
+    fn build_body(&self) -> Value {
+        let mut body = Map::new();
+
+        if let Some(input) = &self.input {
+            body.insert("input".into(), json!(input));
+        }
+
+        if let Some(input_offset) = self.input_offset {
+            body.insert("inputOffset".into(), json!(input_offset));
+        }
+
+        if let Some(included_primary_types) = &self.included_primary_types {
+            let types = included_primary_types
+                .iter()
+                .map(|t| t.to_string())
+                .collect::<Vec<_>>();
+            body.insert("includedPrimaryTypes".into(), json!(types));
+        }
+
+        if let Some(included_region_codes) = &self.included_region_codes {
+            let region_codes = included_region_codes
+                .iter()
+                .map(|c| c.alpha2().to_lowercase())
+                .collect::<Vec<_>>();
+            body.insert("includedRegionCodes".into(), json!(region_codes));
+        }
+
+        if let Some(location_bias) = &self.location_bias {
+            body.insert("locationBias".into(), json!(location_bias));
+        }
+
+        if let Some(location_restriction) = &self.location_restriction {
+            body.insert("locationRestriction".into(), json!(location_restriction));
+        }
+
+        if let Some(origin) = &self.origin {
+            body.insert("origin".into(), json!(origin));
+        }
+
+        if let Some(include_query_predictions) = self.include_query_predictions {
+            body.insert(
+                "includeQueryPredictions".into(),
+                json!(include_query_predictions),
+            );
+        }
+
+        if let Some(language) = &self.language {
+            body.insert("languageCode".into(), json!(language.to_string()));
+        }
+
+        if let Some(region) = &self.region {
+            body.insert("regionCode".into(), json!(region.alpha2()));
+        }
+
+        if let Some(session_token) = &self.session_token {
+            body.insert("sessionToken".into(), json!(session_token));
+        }
+
+        Value::Object(body)
+    }
+
+    /// Execute the Autocomplete call in a non-blocking fashion.
+    ///
+    /// # Errors
+    ///
+    /// If the request fails or the response cannot be parsed, an error is printed and `None` is returned.
+    /// Errors reported by the API are stored in the `error` field of the result.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no input was provided.
+    pub async fn execute(&mut self) -> Option<&mut Autocomplete<'a>> {
+        if self.input.is_none() {
+            panic!("Provide input for Autocomplete query!");
+        }
+
+        let url = "https://places.googleapis.com/v1/places:autocomplete";
+        let request = self.client.post(url).json(&self.build_body());
+
+        let request = match self.credentials.authorize(request).await {
+            Ok(request) => request,
+            Err(err) => {
+                println!("Error authorizing request: {}", err);
+                return None;
+            }
+        };
+
+        let resp = match request.send().await {
+            Ok(resp) => resp,
+            Err(err) => {
+                println!("Error sending request: {:?}", err);
+                return None;
+            }
+        };
+
+        match resp.json::<AutocompleteResult>().await {
+            Ok(query_result) => {
+                self.result = query_result;
+                Some(self)
+            }
+            Err(err) => {
+                println!("Error parsing response: {:?}", err);
+                None
+            }
+        }
+    }
+
+    /// Execute the Autocomplete call in a blocking fashion.
+    #[cfg(feature = "blocking")]
+    pub fn execute_blocking(&mut self) -> Option<&mut Autocomplete<'a>> {
+        tokio::runtime::Runtime::new()
+            .unwrap()
+            .block_on(self.execute())
+    }
+
+    /// Retrieve a cloned `AutocompleteResult`.
+    pub fn get_result(&self) -> AutocompleteResult {
+        self.result.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Autocomplete;
+    use crate::types::constants::PlaceTypes;
+    use crate::types::v1::LatLng;
+    use isocountry::CountryCode;
+    use reqwest::Client;
+    use serde_json::json;
+
+    #[test]
+    fn test_build_body() {
+        let client = Client::new();
+        let mut autocomplete = Autocomplete::new("key".into(), &client);
+        autocomplete
+            .with_input("1600 Amphi")
+            .with_input_offset(10)
+            .with_included_primary_types(vec![PlaceTypes::StreetAddress])
+            .with_included_region_codes(vec![CountryCode::USA])
+            .with_origin(LatLng::new(37.42, -122.08))
+            .with_include_query_predictions(false)
+            .with_session_token("session");
+
+        assert_eq!(
+            autocomplete.build_body(),
+            json!({
+                "input": "1600 Amphi",
+                "inputOffset": 10,
+                "includedPrimaryTypes": ["street_address"],
+                "includedRegionCodes": ["us"],
+                "origin": { "latitude": 37.42, "longitude": -122.08 },
+                "includeQueryPredictions": false,
+                "sessionToken": "session"
+            })
+        );
+    }
+}
@@ -0,0 +1,200 @@
+use crate::endpoints::auth::Credentials;
+use crate::types::v1::{AuthorAttribution, Photo, PhotoMedia};
+use reqwest::Client;
+
+pub struct GetPhotoMedia<'a> {
+    photo_name: Option<String>,
+    author_attributions: Vec<AuthorAttribution>,
+    max_width_px: Option<u32>,
+    max_height_px: Option<u32>,
+    skip_http_redirect: Option<bool>,
+    credentials: Credentials,
+    client: &'a Client,
+    result: PhotoMedia,
+}
+
+impl<'a> GetPhotoMedia<'a> {
+    /// Construct a new `GetPhotoMedia` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - The credentials used to authenticate the request.
+    /// * `client` - A `reqwest::Client` instance.
+    pub fn new(credentials: Credentials, client: &'a Client) -> Self {
+        Self {
+            photo_name: None,
+            author_attributions: vec![],
+            max_width_px: None,
+            max_height_px: None,
+            skip_http_redirect: None,
+            credentials,
+            client,
+            result: Default::default(),
+        }
+    }
+
+    /// Set the photo to fetch, keeping its author attributions for the result.
+    pub fn with_photo(&mut self, photo: &Photo) -> &mut GetPhotoMedia<'a> {
+        self.photo_name = Some(photo.name.clone());
+        self.author_attributions = photo.author_attributions.clone().unwrap_or_default();
+        self
+    }
+
+    /// Set the resource name of the photo to fetch, `places/{place_id}/photos/{photo}`.
+    pub fn with_photo_name(&mut self, photo_name: &str) -> &mut GetPhotoMedia<'a> {
+        self.photo_name = Some(String::from(photo_name));
+        self
+    }
+
+    /// Set the maximum width of the image, between 1 and 4800.
+    pub fn with_max_width_px(&mut self, max_width_px: u32) -> &mut GetPhotoMedia<'a> {
+        self.max_width_px = Some(max_width_px);
+        self
+    }
+
+    /// Set the maximum height of the image, between 1 and 4800.
+    pub fn with_max_height_px(&mut self, max_height_px: u32) -> &mut GetPhotoMedia<'a> {
+        self.max_height_px = Some(max_height_px);
+        self
+    }
+
+    /// When `true`, return the `photo_uri` of the image instead of its bytes.
+    pub fn with_skip_http_redirect(&mut self, skip_http_redirect: bool) -> &mut GetPhotoMedia<'a> {
+        self.skip_http_redirect = Some(skip_http_redirect);
+        self
+    }
+
+    fn build_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = vec![];
+
+        if let Some(max_width_px) = self.max_width_px {
+            params.push(("maxWidthPx", max_width_px.to_string()));
+        }
+
+        if let Some(max_height_px) = self.max_height_px {
+            params.push(("maxHeightPx", max_height_px.to_string()));
+        }
+
+        if let Some(skip_http_redirect) = self.skip_http_redirect {
+            params.push(("skipHttpRedirect", skip_http_redirect.to_string()));
+        }
+
+        params
+    }
+
+    /// Execute the GetPhotoMedia call in a non-blocking fashion.
+    ///
+    /// # Errors
+    ///
+    /// If the request fails or the response cannot be read, an error is printed and `None` is returned.
+    /// Errors reported by the API are stored in the `error` field of the result.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no photo name or neither a maximum width nor a maximum height was provided.
+    pub async fn execute(&mut self) -> Option<&mut GetPhotoMedia<'a>> {
+        let photo_name = match &self.photo_name {
+            Some(photo_name) => photo_name,
+            None => panic!("Provide photo name for GetPhotoMedia query!"),
+        };
+        if self.max_width_px.is_none() && self.max_height_px.is_none() {
+            panic!("Provide max_width_px or max_height_px for GetPhotoMedia query!");
+        }
+
+        let url = format!("https://places.googleapis.com/v1/{}/media", photo_name);
+        let request = self.client.get(url).query(&self.build_params());
+
+        let request = match self.credentials.authorize(request).await {
+            Ok(request) => request,
+            Err(err) => {
+                println!("Error authorizing request: {}", err);
+                return None;
+            }
+        };
+
+        let resp = match request.send().await {
+            Ok(resp) => resp,
+            Err(err) => {
+                println!("Error sending request: {:?}", err);
+                return None;
+            }
+        };
+
+        let is_json = resp
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .is_some_and(|v| v.starts_with("application/json"));
+
+        let query_result = if is_json {
+            resp.json::<PhotoMedia>().await
+        } else {
+            resp.bytes().await.map(|bytes| PhotoMedia {
+                name: self.photo_name.clone(),
+                bytes: Some(bytes.to_vec()),
+                ..Default::default()
+            })
+        };
+
+        match query_result {
+            Ok(mut query_result) => {
+                query_result.author_attributions = self.author_attributions.clone();
+                self.result = query_result;
+                Some(self)
+            }
+            Err(err) => {
+                println!("Error parsing response: {:?}", err);
+                None
+            }
+        }
+    }
+
+    /// Execute the GetPhotoMedia call in a blocking fashion.
+    #[cfg(feature = "blocking")]
+    pub fn execute_blocking(&mut self) -> Option<&mut GetPhotoMedia<'a>> {
+        tokio::runtime::Runtime::new()
+            .unwrap()
+            .block_on(self.execute())
+    }
+
+    /// Retrieve a cloned `PhotoMedia`.
+    pub fn get_result(&self) -> PhotoMedia {
+        self.result.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GetPhotoMedia;
+    use crate::types::v1::{AuthorAttribution, Photo};
+    use reqwest::Client;
+
+    #[test]
+    fn test_with_photo() {
+        let client = Client::new();
+        let photo = Photo {
+            name: String::from("places/abc/photos/def"),
+            author_attributions: Some(vec![AuthorAttribution {
+                display_name: Some(String::from("Jane")),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        };
+
+        let mut media = GetPhotoMedia::new("key".into(), &client);
+        media
+            .with_photo(&photo)
+            .with_max_width_px(800)
+            .with_skip_http_redirect(true);
+
+        assert_eq!(media.photo_name.as_deref(), Some("places/abc/photos/def"));
+        assert_eq!(media.author_attributions.len(), 1);
+        assert_eq!(
+            media.build_params(),
+            vec![
+                ("maxWidthPx", String::from("800")),
+                ("skipHttpRedirect", String::from("true")),
+            ]
+        );
+    }
+}
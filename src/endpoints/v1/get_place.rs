@@ -0,0 +1,202 @@
+use crate::endpoints::auth::Credentials;
+use crate::types::constants::Language;
+use crate::types::v1::{GetPlaceResult, PlaceFields};
+use isocountry::CountryCode;
+use reqwest::Client;
+use std::collections::HashSet;
+
+pub struct GetPlace<'a> {
+    place_id: Option<String>,
+    fields: Option<HashSet<PlaceFields>>,
+    language: Option<Language>,
+    region: Option<CountryCode>,
+    session_token: Option<String>,
+    credentials: Credentials,
+    client: &'a Client,
+    result: GetPlaceResult,
+}
+
+impl<'a> GetPlace<'a> {
+    /// Construct a new `GetPlace` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - The credentials used to authenticate the request.
+    /// * `client` - A `reqwest::Client` instance.
+    pub fn new(credentials: Credentials, client: &'a Client) -> Self {
+        Self {
+            place_id: None,
+            fields: None,
+            language: None,
+            region: None,
+            session_token: None,
+            credentials,
+            client,
+            result: Default::default(),
+        }
+    }
+
+    /// Set the id of the place, either as a bare place id or as a `places/{place_id}` resource name.
+    pub fn with_place_id(&mut self, place_id: &str) -> &mut GetPlace<'a> {
+        let place_id = place_id.strip_prefix("places/").unwrap_or(place_id);
+        self.place_id = Some(String::from(place_id));
+        self
+    }
+
+    /// Set the place fields to return.
+    ///
+    /// When no fields are set, every field is requested (`*`).
+    pub fn with_fields(&mut self, fields: HashSet<PlaceFields>) -> &mut GetPlace<'a> {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Set the language in which the place is returned.
+    pub fn with_language(&mut self, language: Language) -> &mut GetPlace<'a> {
+        self.language = Some(language);
+        self
+    }
+
+    /// Set the region used to format the response.
+    pub fn with_region(&mut self, region: CountryCode) -> &mut GetPlace<'a> {
+        self.region = Some(region);
+        self
+    }
+
+    /// Set the session token of the autocomplete session this call concludes.
+    pub fn with_session_token(&mut self, session_token: &str) -> &mut GetPlace<'a> {
+        self.session_token = Some(String::from(session_token));
+        self
+    }
+
+    fn build_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = vec![];
+
+        if let Some(language) = &self.language {
+            params.push(("languageCode", language.to_string()));
+        }
+
+        if let Some(region) = &self.region {
+            params.push(("regionCode", region.alpha2().to_string()));
+        }
+
+        if let Some(session_token) = &self.session_token {
+            params.push(("sessionToken", session_token.clone()));
+        }
+
+        params
+    }
+
+    fn build_field_mask(&self) -> String {
+        match &self.fields {
+            Some(fields) if !fields.is_empty() => PlaceFields::field_mask(fields, ""),
+            _ => String::from("*"),
+        }
+    }
+
+    /// Execute the GetPlace call in a non-blocking fashion.
+    ///
+    /// # Errors
+    ///
+    /// If the request fails or the response cannot be parsed, an error is printed and `None` is returned.
+    /// Errors reported by the API are stored in the `error` field of the result.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no place id was provided.
+    pub async fn execute(&mut self) -> Option<&mut GetPlace<'a>> {
+        let place_id = match &self.place_id {
+            Some(place_id) => place_id,
+            None => panic!("Provide place_id for GetPlace query!"),
+        };
+
+        let url = format!("https://places.googleapis.com/v1/places/{}", place_id);
+        let request = self
+            .client
+            .get(url)
+            .query(&self.build_params())
+            .header("X-Goog-FieldMask", self.build_field_mask());
+
+        let request = match self.credentials.authorize(request).await {
+            Ok(request) => request,
+            Err(err) => {
+                println!("Error authorizing request: {}", err);
+                return None;
+            }
+        };
+
+        let resp = match request.send().await {
+            Ok(resp) => resp,
+            Err(err) => {
+                println!("Error sending request: {:?}", err);
+                return None;
+            }
+        };
+
+        match resp.json::<GetPlaceResult>().await {
+            Ok(query_result) => {
+                self.result = query_result;
+                Some(self)
+            }
+            Err(err) => {
+                println!("Error parsing response: {:?}", err);
+                None
+            }
+        }
+    }
+
+    /// Execute the GetPlace call in a blocking fashion.
+    #[cfg(feature = "blocking")]
+    pub fn execute_blocking(&mut self) -> Option<&mut GetPlace<'a>> {
+        tokio::runtime::Runtime::new()
+            .unwrap()
+            .block_on(self.execute())
+    }
+
+    /// Retrieve a cloned `GetPlaceResult`.
+    pub fn get_result(&self) -> GetPlaceResult {
+        self.result.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GetPlace;
+    use crate::types::constants::Language;
+    use crate::types::v1::PlaceFields;
+    use isocountry::CountryCode;
+    use reqwest::Client;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_build_params() {
+        let client = Client::new();
+        let mut get_place = GetPlace::new("key".into(), &client);
+        get_place
+            .with_place_id("places/ChIJj61dQgK6j4AR4GeTYWZsKWw")
+            .with_fields(HashSet::from([
+                PlaceFields::DisplayName,
+                PlaceFields::RegularOpeningHours,
+            ]))
+            .with_language(Language::En)
+            .with_region(CountryCode::USA)
+            .with_session_token("session");
+
+        assert_eq!(
+            get_place.place_id.as_deref(),
+            Some("ChIJj61dQgK6j4AR4GeTYWZsKWw")
+        );
+        assert_eq!(
+            get_place.build_field_mask(),
+            "displayName,regularOpeningHours"
+        );
+        assert_eq!(
+            get_place.build_params(),
+            vec![
+                ("languageCode", String::from("en")),
+                ("regionCode", String::from("US")),
+                ("sessionToken", String::from("session")),
+            ]
+        );
+    }
+}
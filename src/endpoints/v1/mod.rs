@@ -0,0 +1,6 @@
+pub mod autocomplete;
+pub mod get_photo_media;
+pub mod get_place;
+pub mod places;
+pub mod search_nearby;
+pub mod search_text;
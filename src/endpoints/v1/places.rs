@@ -0,0 +1,59 @@
+use crate::endpoints::auth::Credentials;
+use crate::endpoints::v1::autocomplete::Autocomplete;
+use crate::endpoints::v1::get_photo_media::GetPhotoMedia;
+use crate::endpoints::v1::get_place::GetPlace;
+use crate::endpoints::v1::search_nearby::SearchNearby;
+use crate::endpoints::v1::search_text::SearchText;
+use reqwest::Client;
+
+pub struct Places<'a> {
+    credentials: Credentials,
+    client: &'a Client,
+}
+
+impl<'a> Places<'a> {
+    /// Constructs a new `Places` instance for the Places API (New).
+    ///
+    /// ## DO NOT USE THIS ALONE, USE THE `GooglePlacesAPI` STRUCT.
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - The API key or service account used to authenticate requests.
+    /// * `client` - A reference to a `reqwest::Client` for executing HTTP requests.
+    pub fn new(credentials: Credentials, client: &'a Client) -> Self {
+        Self {
+            credentials,
+            client,
+        }
+    }
+
+    /// Returns a new `SearchText` instance that can be used to execute a
+    /// `places:searchText` request.
+    pub fn search_text(&mut self) -> SearchText<'a> {
+        SearchText::new(self.credentials.clone(), self.client)
+    }
+
+    /// Returns a new `SearchNearby` instance that can be used to execute a
+    /// `places:searchNearby` request.
+    pub fn search_nearby(&mut self) -> SearchNearby<'a> {
+        SearchNearby::new(self.credentials.clone(), self.client)
+    }
+
+    /// Returns a new `GetPlace` instance that can be used to execute a
+    /// `places/{place_id}` details request.
+    pub fn get_place(&mut self) -> GetPlace<'a> {
+        GetPlace::new(self.credentials.clone(), self.client)
+    }
+
+    /// Returns a new `GetPhotoMedia` instance that can be used to fetch the
+    /// media of a photo returned by the Places API (New).
+    pub fn get_photo_media(&mut self) -> GetPhotoMedia<'a> {
+        GetPhotoMedia::new(self.credentials.clone(), self.client)
+    }
+
+    /// Returns a new `Autocomplete` instance that can be used to execute a
+    /// `places:autocomplete` request.
+    pub fn autocomplete(&mut self) -> Autocomplete<'a> {
+        Autocomplete::new(self.credentials.clone(), self.client)
+    }
+}
@@ -0,0 +1,296 @@
+use crate::endpoints::auth::Credentials;
+use crate::types::constants::{Language, PlaceTypes};
+use crate::types::v1::{
+    LatLng, LocationArea, Place, PlaceFields, RankPreference, SearchNearbyResult,
+};
+use isocountry::CountryCode;
+use reqwest::Client;
+use serde_json::{json, Map, Value};
+use std::collections::HashSet;
+
+pub struct SearchNearby<'a> {
+    location: Option<LatLng>,
+    radius: Option<f64>,
+    included_types: Option<Vec<PlaceTypes>>,
+    excluded_types: Option<Vec<PlaceTypes>>,
+    included_primary_types: Option<Vec<PlaceTypes>>,
+    excluded_primary_types: Option<Vec<PlaceTypes>>,
+    language: Option<Language>,
+    region: Option<CountryCode>,
+    max_result_count: Option<u32>,
+    rank_preference: Option<RankPreference>,
+    fields: Option<HashSet<PlaceFields>>,
+    credentials: Credentials,
+    client: &'a Client,
+    result: SearchNearbyResult,
+}
+
+impl<'a> SearchNearby<'a> {
+    /// Construct a new `SearchNearby` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - The credentials used to authenticate the request.
+    /// * `client` - A `reqwest::Client` instance.
+    pub fn new(credentials: Credentials, client: &'a Client) -> Self {
+        Self {
+            location: None,
+            radius: None,
+            included_types: None,
+            excluded_types: None,
+            included_primary_types: None,
+            excluded_primary_types: None,
+            language: None,
+            region: None,
+            max_result_count: None,
+            rank_preference: None,
+            fields: None,
+            credentials,
+            client,
+            result: Default::default(),
+        }
+    }
+
+    /// Set the center of the circle the results are restricted to.
+    pub fn with_location(&mut self, location: LatLng) -> &mut SearchNearby<'a> {
+        self.location = Some(location);
+        self
+    }
+
+    /// Set the radius in meters of the circle the results are restricted to, up to 50000.
+    pub fn with_radius(&mut self, radius: f64) -> &mut SearchNearby<'a> {
+        self.radius = Some(radius);
+        self
+    }
+
+    /// Only return places having at least one of the given types.
+    pub fn with_included_types(
+        &mut self,
+        included_types: Vec<PlaceTypes>,
+    ) -> &mut SearchNearby<'a> {
+        self.included_types = Some(included_types);
+        self
+    }
+
+    /// Exclude places having any of the given types.
+    pub fn with_excluded_types(
+        &mut self,
+        excluded_types: Vec<PlaceTypes>,
+    ) -> &mut SearchNearby<'a> {
+        self.excluded_types = Some(excluded_types);
+        self
+    }
+
+    /// Only return places whose primary type is one of the given types.
+    pub fn with_included_primary_types(
+        &mut self,
+        included_primary_types: Vec<PlaceTypes>,
+    ) -> &mut SearchNearby<'a> {
+        self.included_primary_types = Some(included_primary_types);
+        self
+    }
+
+    /// Exclude places whose primary type is one of the given types.
+    pub fn with_excluded_primary_types(
+        &mut self,
+        excluded_primary_types: Vec<PlaceTypes>,
+    ) -> &mut SearchNearby<'a> {
+        self.excluded_primary_types = Some(excluded_primary_types);
+        self
+    }
+
+    /// Set the language in which the results are returned.
+    pub fn with_language(&mut self, language: Language) -> &mut SearchNearby<'a> {
+        self.language = Some(language);
+        self
+    }
+
+    /// Set the region used to format the response.
+    pub fn with_region(&mut self, region: CountryCode) -> &mut SearchNearby<'a> {
+        self.region = Some(region);
+        self
+    }
+
+    /// Set the maximum number of results to return, between 1 and 20.
+    pub fn with_max_result_count(&mut self, max_result_count: u32) -> &mut SearchNearby<'a> {
+        self.max_result_count = Some(max_result_count);
+        self
+    }
+
+    /// Set how the results are ranked, either by popularity or by distance.
+    pub fn with_rank_preference(
+        &mut self,
+        rank_preference: RankPreference,
+    ) -> &mut SearchNearby<'a> {
+        self.rank_preference = Some(rank_preference);
+        self
+    }
+
+    /// Set the place fields returned for each result.
+    ///
+    /// When no fields are set, every field is requested (`*`).
+    pub fn with_fields(&mut self, fields: HashSet<PlaceFields>) -> &mut SearchNearby<'a> {
+        self.fields = Some(fields);
+        self
+    }
+
+    fn build_body(&self) -> Value {
+        let mut body = Map::new();
+
+        if let (Some(location), Some(radius)) = (&self.location, self.radius) {
+            body.insert(
+                "locationRestriction".into(),
+                json!(LocationArea::circle(*location, radius)),
+            );
+        }
+
+        let type_lists = [
+            ("includedTypes", &self.included_types),
+            ("excludedTypes", &self.excluded_types),
+            ("includedPrimaryTypes", &self.included_primary_types),
+            ("excludedPrimaryTypes", &self.excluded_primary_types),
+        ];
+        for (key, types) in type_lists {
+            if let Some(types) = types {
+                let types = types.iter().map(|t| t.to_string()).collect::<Vec<_>>();
+                body.insert(key.into(), json!(types));
+            }
+        }
+
+        if let Some(language) = &self.language {
+            body.insert("languageCode".into(), json!(language.to_string()));
+        }
+
+        if let Some(region) = &self.region {
+            body.insert("regionCode".into(), json!(region.alpha2()));
+        }
+
+        if let Some(max_result_count) = self.max_result_count {
+            body.insert("maxResultCount".into(), json!(max_result_count));
+        }
+
+        if let Some(rank_preference) = &self.rank_preference {
+            body.insert("rankPreference".into(), json!(rank_preference));
+        }
+
+        Value::Object(body)
+    }
+
+    fn build_field_mask(&self) -> String {
+        match &self.fields {
+            Some(fields) if !fields.is_empty() => PlaceFields::field_mask(fields, "places."),
+            _ => String::from("*"),
+        }
+    }
+
+    /// Execute the SearchNearby call in a non-blocking fashion.
+    ///
+    /// # Errors
+    ///
+    /// If the request fails or the response cannot be parsed, an error is printed and `None` is returned.
+    /// Errors reported by the API are stored in the `error` field of the result.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the location or the radius was not provided.
+    pub async fn execute(&mut self) -> Option<&mut SearchNearby<'a>> {
+        if self.location.is_none() || self.radius.is_none() {
+            panic!("Location and radius must be provided for SearchNearby.");
+        }
+
+        let url = "https://places.googleapis.com/v1/places:searchNearby";
+        let request = self
+            .client
+            .post(url)
+            .header("X-Goog-FieldMask", self.build_field_mask())
+            .json(&self.build_body());
+
+        let request = match self.credentials.authorize(request).await {
+            Ok(request) => request,
+            Err(err) => {
+                println!("Error authorizing request: {}", err);
+                return None;
+            }
+        };
+
+        let resp = match request.send().await {
+            Ok(resp) => resp,
+            Err(err) => {
+                println!("Error sending request: {:?}", err);
+                return None;
+            }
+        };
+
+        match resp.json::<SearchNearbyResult>().await {
+            Ok(query_result) => {
+                self.result = query_result;
+                Some(self)
+            }
+            Err(err) => {
+                println!("Error parsing response: {:?}", err);
+                None
+            }
+        }
+    }
+
+    /// Execute the SearchNearby call in a blocking fashion.
+    #[cfg(feature = "blocking")]
+    pub fn execute_blocking(&mut self) -> Option<&mut SearchNearby<'a>> {
+        tokio::runtime::Runtime::new()
+            .unwrap()
+            .block_on(self.execute())
+    }
+
+    /// Returns an iterator over the places found by the search.
+    pub fn iter(&self) -> std::slice::Iter<'_, Place> {
+        self.result.places.iter()
+    }
+
+    /// Retrieve the place at the specified index.
+    pub fn at(&self, index: usize) -> Option<&Place> {
+        self.result.places.get(index)
+    }
+
+    /// Retrieve a cloned `SearchNearbyResult`.
+    pub fn get_result(&self) -> SearchNearbyResult {
+        self.result.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SearchNearby;
+    use crate::types::constants::PlaceTypes;
+    use crate::types::v1::{LatLng, RankPreference};
+    use reqwest::Client;
+    use serde_json::json;
+
+    #[test]
+    fn test_build_body() {
+        let client = Client::new();
+        let mut search = SearchNearby::new("key".into(), &client);
+        search
+            .with_location(LatLng::new(46.7749, 23.62))
+            .with_radius(1000.0)
+            .with_included_types(vec![PlaceTypes::Cafe, PlaceTypes::Bakery])
+            .with_excluded_primary_types(vec![PlaceTypes::GasStation])
+            .with_max_result_count(10)
+            .with_rank_preference(RankPreference::Distance);
+
+        assert_eq!(
+            search.build_body(),
+            json!({
+                "locationRestriction": {
+                    "circle": {
+                        "center": { "latitude": 46.7749, "longitude": 23.62 },
+                        "radius": 1000.0
+                    }
+                },
+                "includedTypes": ["cafe", "bakery"],
+                "excludedPrimaryTypes": ["gas_station"],
+                "maxResultCount": 10,
+                "rankPreference": "DISTANCE"
+            })
+        );
+    }
+}
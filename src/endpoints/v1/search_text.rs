@@ -0,0 +1,351 @@
+use crate::endpoints::auth::Credentials;
+use crate::types::constants::{Language, PlaceTypes};
+use crate::types::v1::{
+    LocationArea, Place, PlaceFields, PriceLevel, RankPreference, SearchTextResult,
+};
+use isocountry::CountryCode;
+use reqwest::Client;
+use serde_json::{json, Map, Value};
+use std::collections::HashSet;
+
+pub struct SearchText<'a> {
+    text_query: Option<String>,
+    included_type: Option<PlaceTypes>,
+    language: Option<Language>,
+    region: Option<CountryCode>,
+    location_bias: Option<LocationArea>,
+    location_restriction: Option<LocationArea>,
+    price_levels: Option<Vec<PriceLevel>>,
+    min_rating: Option<f64>,
+    open_now: Option<bool>,
+    page_size: Option<u32>,
+    page_token: Option<String>,
+    rank_preference: Option<RankPreference>,
+    fields: Option<HashSet<PlaceFields>>,
+    credentials: Credentials,
+    client: &'a Client,
+    result: SearchTextResult,
+}
+
+impl<'a> SearchText<'a> {
+    /// Construct a new `SearchText` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - The credentials used to authenticate the request.
+    /// * `client` - A `reqwest::Client` instance.
+    pub fn new(credentials: Credentials, client: &'a Client) -> Self {
+        Self {
+            text_query: None,
+            included_type: None,
+            language: None,
+            region: None,
+            location_bias: None,
+            location_restriction: None,
+            price_levels: None,
+            min_rating: None,
+            open_now: None,
+            page_size: None,
+            page_token: None,
+            rank_preference: None,
+            fields: None,
+            credentials,
+            client,
+            result: Default::default(),
+        }
+    }
+
+    /// Set the text query, e.g. `"pizza in New York"`.
+    pub fn with_query(&mut self, text_query: &str) -> &mut SearchText<'a> {
+        self.text_query = Some(String::from(text_query));
+        self
+    }
+
+    /// Restrict the results to places of the given type.
+    pub fn with_included_type(&mut self, included_type: PlaceTypes) -> &mut SearchText<'a> {
+        self.included_type = Some(included_type);
+        self
+    }
+
+    /// Set the language in which the results are returned.
+    pub fn with_language(&mut self, language: Language) -> &mut SearchText<'a> {
+        self.language = Some(language);
+        self
+    }
+
+    /// Set the region used to format the response.
+    pub fn with_region(&mut self, region: CountryCode) -> &mut SearchText<'a> {
+        self.region = Some(region);
+        self
+    }
+
+    /// Prefer results in the given area. Cannot be combined with a location restriction.
+    pub fn with_location_bias(&mut self, location_bias: LocationArea) -> &mut SearchText<'a> {
+        self.location_bias = Some(location_bias);
+        self
+    }
+
+    /// Only return results in the given area. Only rectangles are accepted by the API.
+    pub fn with_location_restriction(
+        &mut self,
+        location_restriction: LocationArea,
+    ) -> &mut SearchText<'a> {
+        self.location_restriction = Some(location_restriction);
+        self
+    }
+
+    /// Only return places whose price level is one of `price_levels`.
+    pub fn with_price_levels(&mut self, price_levels: Vec<PriceLevel>) -> &mut SearchText<'a> {
+        self.price_levels = Some(price_levels);
+        self
+    }
+
+    /// Only return places with an average rating greater than or equal to `min_rating`.
+    ///
+    /// The API rounds the value up to the nearest 0.5.
+    pub fn with_min_rating(&mut self, min_rating: f64) -> &mut SearchText<'a> {
+        self.min_rating = Some(min_rating);
+        self
+    }
+
+    /// Only return places that are open at the time of the query.
+    pub fn with_open_now(&mut self, open_now: bool) -> &mut SearchText<'a> {
+        self.open_now = Some(open_now);
+        self
+    }
+
+    /// Set the number of results per page, between 1 and 20.
+    pub fn with_page_size(&mut self, page_size: u32) -> &mut SearchText<'a> {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Set the token of the page to fetch, as returned in `next_page_token`.
+    pub fn with_page_token(&mut self, page_token: &str) -> &mut SearchText<'a> {
+        self.page_token = Some(String::from(page_token));
+        self
+    }
+
+    /// Set how the results are ranked.
+    pub fn with_rank_preference(&mut self, rank_preference: RankPreference) -> &mut SearchText<'a> {
+        self.rank_preference = Some(rank_preference);
+        self
+    }
+
+    /// Set the place fields returned for each result.
+    ///
+    /// When no fields are set, every field is requested (`*`).
+    pub fn with_fields(&mut self, fields: HashSet<PlaceFields>) -> &mut SearchText<'a> {
+        self.fields = Some(fields);
+        self
+    }
+
+    fn build_body(&self) -> Value {
+        let mut body = Map::new();
+
+        if let Some(text_query) = &self.text_query {
+            body.insert("textQuery".into(), json!(text_query));
+        }
+
+        if let Some(included_type) = &self.included_type {
+            body.insert("includedType".into(), json!(included_type.to_string()));
+        }
+
+        if let Some(language) = &self.language {
+            body.insert("languageCode".into(), json!(language.to_string()));
+        }
+
+        if let Some(region) = &self.region {
+            body.insert("regionCode".into(), json!(region.alpha2()));
+        }
+
+        if let Some(location_bias) = &self.location_bias {
+            body.insert("locationBias".into(), json!(location_bias));
+        }
+
+        if let Some(location_restriction) = &self.location_restriction {
+            body.insert("locationRestriction".into(), json!(location_restriction));
+        }
+
+        if let Some(price_levels) = &self.price_levels {
+            body.insert("priceLevels".into(), json!(price_levels));
+        }
+
+        if let Some(min_rating) = self.min_rating {
+            body.insert("minRating".into(), json!(min_rating));
+        }
+
+        if let Some(open_now) = self.open_now {
+            body.insert("openNow".into(), json!(open_now));
+        }
+
+        if let Some(page_size) = self.page_size {
+            body.insert("pageSize".into(), json!(page_size));
+        }
+
+        if let Some(page_token) = &self.page_token {
+            body.insert("pageToken".into(), json!(page_token));
+        }
+
+        if let Some(rank_preference) = &self.rank_preference {
+            body.insert("rankPreference".into(), json!(rank_preference));
+        }
+
+        Value::Object(body)
+    }
+
+    fn build_field_mask(&self) -> String {
+        match &self.fields {
+            Some(fields) if !fields.is_empty() => {
+                format!(
+                    "{},nextPageToken",
+                    PlaceFields::field_mask(fields, "places.")
+                )
+            }
+            _ => String::from("*"),
+        }
+    }
+
+    /// Execute the SearchText call in a non-blocking fashion.
+    ///
+    /// Follows `next_page_token` until `max_pages` pages have been fetched or no
+    /// more pages are available.
+    ///
+    /// # Errors
+    ///
+    /// If the request fails or the response cannot be parsed, an error is printed and `None` is returned.
+    /// Errors reported by the API are stored in the `error` field of the result.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no text query was provided.
+    pub async fn execute(&mut self, max_pages: usize) -> Option<&mut SearchText<'a>> {
+        if self.text_query.is_none() {
+            panic!("Provide text_query for SearchText query!");
+        }
+
+        let url = "https://places.googleapis.com/v1/places:searchText";
+        let field_mask = self.build_field_mask();
+        let mut page_count = 0;
+
+        while page_count < max_pages {
+            let request = self
+                .client
+                .post(url)
+                .header("X-Goog-FieldMask", field_mask.as_str())
+                .json(&self.build_body());
+
+            let request = match self.credentials.authorize(request).await {
+                Ok(request) => request,
+                Err(err) => {
+                    println!("Error authorizing request: {}", err);
+                    return None;
+                }
+            };
+
+            let resp = match request.send().await {
+                Ok(resp) => resp,
+                Err(err) => {
+                    println!("Error sending request: {:?}", err);
+                    return None;
+                }
+            };
+
+            match resp.json::<SearchTextResult>().await {
+                Ok(query_result) => {
+                    if page_count == 0 || query_result.error.is_some() {
+                        self.result.places.clear();
+                        self.result.error = query_result.error.clone();
+                    }
+                    self.result.places.extend(query_result.places);
+                    self.result.next_page_token = query_result.next_page_token.clone();
+
+                    match (query_result.error, query_result.next_page_token) {
+                        (None, Some(next_page_token)) => {
+                            self.page_token = Some(next_page_token);
+                            page_count += 1;
+                        }
+                        _ => break,
+                    }
+                }
+                Err(err) => {
+                    println!("Error parsing response: {:?}", err);
+                    return None;
+                }
+            }
+        }
+
+        Some(self)
+    }
+
+    /// Execute the SearchText call in a blocking fashion.
+    #[cfg(feature = "blocking")]
+    pub fn execute_blocking(&mut self, max_pages: usize) -> Option<&mut SearchText<'a>> {
+        tokio::runtime::Runtime::new()
+            .unwrap()
+            .block_on(self.execute(max_pages))
+    }
+
+    /// Returns an iterator over the places found by the search.
+    pub fn iter(&self) -> std::slice::Iter<'_, Place> {
+        self.result.places.iter()
+    }
+
+    /// Retrieve the place at the specified index.
+    pub fn at(&self, index: usize) -> Option<&Place> {
+        self.result.places.get(index)
+    }
+
+    /// Retrieve a cloned `SearchTextResult`.
+    pub fn get_result(&self) -> SearchTextResult {
+        self.result.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SearchText;
+    use crate::types::constants::PlaceTypes;
+    use crate::types::v1::{PlaceFields, PriceLevel, RankPreference};
+    use reqwest::Client;
+    use serde_json::json;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_build_body() {
+        let client = Client::new();
+        let mut search = SearchText::new("key".into(), &client);
+        search
+            .with_query("coffee")
+            .with_included_type(PlaceTypes::Cafe)
+            .with_price_levels(vec![PriceLevel::Inexpensive, PriceLevel::Moderate])
+            .with_min_rating(4.0)
+            .with_open_now(true)
+            .with_rank_preference(RankPreference::Distance);
+
+        assert_eq!(
+            search.build_body(),
+            json!({
+                "textQuery": "coffee",
+                "includedType": "cafe",
+                "priceLevels": ["PRICE_LEVEL_INEXPENSIVE", "PRICE_LEVEL_MODERATE"],
+                "minRating": 4.0,
+                "openNow": true,
+                "rankPreference": "DISTANCE"
+            })
+        );
+    }
+
+    #[test]
+    fn test_build_field_mask() {
+        let client = Client::new();
+        let mut search = SearchText::new("key".into(), &client);
+        assert_eq!(search.build_field_mask(), "*");
+
+        search.with_fields(HashSet::from([PlaceFields::Id, PlaceFields::Rating]));
+        assert_eq!(
+            search.build_field_mask(),
+            "places.id,places.rating,nextPageToken"
+        );
+    }
+}
@@ -15,4 +15,4 @@ into their Rust applications.
 
 */
 
-pub use endpoints::api::GooglePlacesAPI;
+pub use endpoints::api::{GooglePlacesAPI, MissingApiKey};
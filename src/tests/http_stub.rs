@@ -0,0 +1,80 @@
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+/// A request received by the stub server.
+pub struct StubRequest {
+    pub body: String,
+}
+
+/// Starts a local HTTP server on a random port and returns its base URL, e.g. `http://127.0.0.1:1234`.
+///
+/// Every connection serves one request. `respond` is called with the number of the request,
+/// starting at 1, and returns the raw response written chunk by chunk, flushing in between.
+pub async fn serve<F>(mut respond: F) -> String
+where
+    F: FnMut(usize, &StubRequest) -> Vec<Vec<u8>> + Send + 'static,
+{
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+
+    tokio::spawn(async move {
+        let mut count = 0;
+        loop {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let request = read_request(&mut socket).await;
+            count += 1;
+            for chunk in respond(count, &request) {
+                socket.write_all(&chunk).await.unwrap();
+                socket.flush().await.unwrap();
+            }
+        }
+    });
+
+    url
+}
+
+/// A complete response with a `Content-Length`, e.g. `response("200 OK", &[], b"")`.
+pub fn response(status: &str, headers: &[(&str, &str)], body: &[u8]) -> Vec<u8> {
+    let mut response = format!("HTTP/1.1 {}\r\n", status);
+    for (name, value) in headers {
+        response.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    response.push_str(&format!(
+        "Content-Length: {}\r\nConnection: close\r\n\r\n",
+        body.len()
+    ));
+
+    let mut response = response.into_bytes();
+    response.extend_from_slice(body);
+    response
+}
+
+async fn read_request(socket: &mut TcpStream) -> StubRequest {
+    let mut request = Vec::new();
+    let mut buf = [0u8; 4096];
+    loop {
+        let n = socket.read(&mut buf).await.unwrap();
+        request.extend_from_slice(&buf[..n]);
+        let text = String::from_utf8_lossy(&request);
+        if let Some((head, body)) = text.split_once("\r\n\r\n") {
+            let length = head
+                .lines()
+                .find_map(|l| {
+                    l.to_lowercase()
+                        .strip_prefix("content-length:")
+                        .map(|v| v.trim().parse::<usize>().unwrap())
+                })
+                .unwrap_or(0);
+            if body.len() >= length {
+                return StubRequest {
+                    body: body.to_string(),
+                };
+            }
+        }
+        if n == 0 {
+            return StubRequest {
+                body: String::new(),
+            };
+        }
+    }
+}
@@ -1 +1,4 @@
-pub mod tests;
\ No newline at end of file
+pub mod tests;
+
+#[cfg(test)]
+pub mod http_stub;
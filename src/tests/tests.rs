@@ -20,6 +20,7 @@ mod tests {
         let places_api = GooglePlacesAPI::from_env();
         let result = places_api
             .place_search()
+            .unwrap()
             .text_search()
             .with_query("coffee")
             .execute(3)
@@ -47,6 +48,7 @@ mod tests {
         let places_api = GooglePlacesAPI::from_env();
         let result = places_api
             .place_search()
+            .unwrap()
             .text_search()
             .with_query("coffee")
             .with_radius(1000.0)
@@ -75,6 +77,7 @@ mod tests {
         let places_api = GooglePlacesAPI::from_env();
         let result = places_api
             .place_search()
+            .unwrap()
             .nearby_search()
             .with_location(Location::new(46.7749, 23.62))
             .with_radius(1000.0)
@@ -104,6 +107,7 @@ mod tests {
         let places_api = GooglePlacesAPI::from_env();
         let result = places_api
             .place_search()
+            .unwrap()
             .text_search()
             .with_query("coffee")
             .with_language(Language::En)
@@ -132,6 +136,7 @@ mod tests {
 
         let results: Vec<_> = places_api
             .place_search()
+            .unwrap()
             .text_search()
             .with_query("coffee")
             .with_language(Language::En)
@@ -147,6 +152,7 @@ mod tests {
 
             if let Some(detail_query_result) = places_api
                 .place_search()
+                .unwrap()
                 .place_details()
                 .with_place_id(&place_id)
                 .execute()
@@ -167,6 +173,7 @@ mod tests {
 
         let tasks: Vec<_> = places_api
             .place_search()
+            .unwrap()
             .text_search()
             .with_query("coffee")
             .with_language(Language::En)
@@ -184,6 +191,7 @@ mod tests {
                 async move {
                     if let Some(detail_query_result) = places_api
                         .place_search()
+                        .unwrap()
                         .place_details()
                         .with_place_id(&place_id)
                         .execute()
@@ -210,6 +218,7 @@ mod tests {
 
         let tasks: Vec<_> = places_api
             .place_search()
+            .unwrap()
             .nearby_search()
             .with_location(Location::new(46.7749, 23.62))
             .with_radius(10000.0)
@@ -228,6 +237,7 @@ mod tests {
                 async move {
                     if let Some(detail_query_result) = places_api
                         .place_search()
+                        .unwrap()
                         .place_details()
                         .with_place_id(&place_id)
                         .with_fields(HashSet::from([
@@ -258,6 +268,7 @@ mod tests {
 
         let tasks: Vec<_> = places_api
             .place_search()
+            .unwrap()
             .nearby_search()
             .with_location(Location::new(46.7749, 23.62))
             .with_radius(10000.0)
@@ -276,6 +287,7 @@ mod tests {
                 async move {
                     if let Some(detail_query_result) = places_api
                         .place_search()
+                        .unwrap()
                         .place_details()
                         .with_place_id(&place_id)
                         .with_fields(HashSet::from([
@@ -306,6 +318,7 @@ mod tests {
         let places_api = GooglePlacesAPI::from_env();
         let result = places_api
             .place_search()
+            .unwrap()
             .find_place()
             .with_input("coffee")
             .with_input_type("textquery")
@@ -337,6 +350,7 @@ mod tests {
         let places_api = GooglePlacesAPI::from_env();
         let find_place = places_api
             .place_search()
+            .unwrap()
             .find_place()
             .with_input("coffee")
             .with_input_type("textquery")
@@ -362,6 +376,7 @@ mod tests {
 
         let photo = places_api
             .place_search()
+            .unwrap()
             .place_photos()
             .with_photo_reference("ATJ83zhSSAtkh5LTozXMhBghqubeOxnZWUV2m7Hv2tQaIzKQJgvZk9yCaEjBW0r0Zx1oJ9RF1G7oeM34sQQMOv8s2zA0sgGBiyBgvdyMxeVByRgHUXmv")
             .execute()
@@ -0,0 +1,453 @@
+use crate::types::constants::{PlaceDetailsPlace, PlaceSearchPlace};
+use crate::types::{
+    v1, FindPlaceSearchResult, NearbySearchResult, Photo, PlaceDetailsResult, TextSearchResult,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// An attribution parsed from the `html_attributions` of a legacy response, e.g.
+/// `Listings by <a href="https://example.com">Example</a>`.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+pub struct HtmlAttribution {
+    /// The text around and inside the anchors, in order.
+    pub segments: Vec<AttributionSegment>,
+}
+
+/// A run of text of an attribution, linked when it is the text of an anchor.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+pub struct AttributionSegment {
+    /// The text, without markup.
+    pub text: String,
+    /// The `href` of the anchor the text is in, if any.
+    pub href: Option<String>,
+}
+
+impl AttributionSegment {
+    /// The href, if it is a http(s) link that is safe to render.
+    fn safe_href(&self) -> Option<&str> {
+        self.href.as_deref().filter(|href| {
+            let href = href.to_ascii_lowercase();
+            href.starts_with("https://") || href.starts_with("http://")
+        })
+    }
+}
+
+impl HtmlAttribution {
+    /// Parses an html attribution, splitting the text of every anchor and its `href` from
+    /// the plain text around it.
+    pub fn parse(html: &str) -> Self {
+        // Lowercasing only changes ASCII letters, the byte offsets still match `html`.
+        let lower = html.to_ascii_lowercase();
+        let mut segments = Vec::new();
+        let mut rest = 0;
+        while let Some(anchor) = lower[rest..].find("<a ").map(|i| rest + i) {
+            let Some(tag_end) = lower[anchor..].find('>').map(|i| anchor + i) else {
+                break;
+            };
+            let close = lower[tag_end..].find("</a>").map(|i| tag_end + i);
+            push_segment(&mut segments, &html[rest..anchor], None);
+            push_segment(
+                &mut segments,
+                &html[tag_end + 1..close.unwrap_or(html.len())],
+                anchor_href(&html[anchor..=tag_end]),
+            );
+            rest = close.map_or(html.len(), |close| close + "</a>".len());
+        }
+        push_segment(&mut segments, &html[rest..], None);
+
+        if let Some(first) = segments.first_mut() {
+            first.text = String::from(first.text.trim_start());
+        }
+        if let Some(last) = segments.last_mut() {
+            last.text = String::from(last.text.trim_end());
+        }
+        segments.retain(|segment| !segment.text.is_empty());
+        Self { segments }
+    }
+
+    fn from_parts(text: Option<&str>, href: Option<&str>) -> Option<Self> {
+        let text = text.map(str::trim).filter(|t| !t.is_empty())?;
+        Some(Self {
+            segments: vec![AttributionSegment {
+                text: String::from(text),
+                href: href.map(String::from),
+            }],
+        })
+    }
+
+    /// The text of the attribution, without markup and links.
+    pub fn text(&self) -> String {
+        self.segments
+            .iter()
+            .map(|segment| segment.text.as_str())
+            .collect()
+    }
+
+    pub fn to_plain_text(&self) -> String {
+        self.render(|segment| match segment.safe_href() {
+            Some(href) => format!("{} ({})", segment.text, href),
+            None => segment.text.clone(),
+        })
+    }
+
+    pub fn to_markdown(&self) -> String {
+        self.render(|segment| {
+            let text = escape_markdown(&segment.text);
+            match segment.safe_href() {
+                Some(href) => format!("[{}](<{}>)", text, href.replace(['<', '>'], "")),
+                None => text,
+            }
+        })
+    }
+
+    /// Renders the attribution as html, escaping the text and dropping non http(s) links.
+    pub fn to_html(&self) -> String {
+        self.render(|segment| {
+            let text = escape_html(&segment.text);
+            match segment.safe_href() {
+                Some(href) => format!(
+                    r#"<a href="{}" target="_blank" rel="noopener noreferrer">{}</a>"#,
+                    escape_html(href),
+                    text
+                ),
+                None => text,
+            }
+        })
+    }
+
+    fn render(&self, render: impl Fn(&AttributionSegment) -> String) -> String {
+        self.segments.iter().map(render).collect()
+    }
+}
+
+/// Adds the text of a piece of html as a segment, unless it is empty.
+fn push_segment(segments: &mut Vec<AttributionSegment>, html: &str, href: Option<String>) {
+    let text = decode_entities(&strip_tags(html));
+    if !text.is_empty() {
+        segments.push(AttributionSegment {
+            text,
+            href: href.map(|href| decode_entities(&href)),
+        });
+    }
+}
+
+/// Types carrying attributions that must be displayed along with their data.
+pub trait Attributed {
+    fn attributions(&self) -> Vec<HtmlAttribution>;
+}
+
+fn parse_all(html_attributions: &[String]) -> Vec<HtmlAttribution> {
+    html_attributions
+        .iter()
+        .map(|html| HtmlAttribution::parse(html))
+        .collect()
+}
+
+fn photo_attributions(photos: &Option<Vec<Photo>>) -> Vec<HtmlAttribution> {
+    photos
+        .iter()
+        .flatten()
+        .flat_map(|p| p.attributions())
+        .collect()
+}
+
+impl Attributed for Photo {
+    fn attributions(&self) -> Vec<HtmlAttribution> {
+        parse_all(&self.html_attributions)
+    }
+}
+
+impl Attributed for PlaceSearchPlace {
+    fn attributions(&self) -> Vec<HtmlAttribution> {
+        photo_attributions(&self.photos)
+    }
+}
+
+impl Attributed for PlaceDetailsPlace {
+    fn attributions(&self) -> Vec<HtmlAttribution> {
+        photo_attributions(&self.photos)
+    }
+}
+
+impl Attributed for NearbySearchResult {
+    fn attributions(&self) -> Vec<HtmlAttribution> {
+        let mut attributions = parse_all(&self.html_attributions);
+        attributions.extend(self.places.iter().flat_map(|p| p.attributions()));
+        attributions
+    }
+}
+
+impl Attributed for TextSearchResult {
+    fn attributions(&self) -> Vec<HtmlAttribution> {
+        let mut attributions = parse_all(&self.html_attributions);
+        attributions.extend(self.places.iter().flat_map(|p| p.attributions()));
+        attributions
+    }
+}
+
+impl Attributed for FindPlaceSearchResult {
+    fn attributions(&self) -> Vec<HtmlAttribution> {
+        self.places.iter().flat_map(|p| p.attributions()).collect()
+    }
+}
+
+impl Attributed for PlaceDetailsResult {
+    fn attributions(&self) -> Vec<HtmlAttribution> {
+        let mut attributions = parse_all(&self.html_attributions);
+        attributions.extend(self.place.attributions());
+        attributions
+    }
+}
+
+impl Attributed for v1::Photo {
+    fn attributions(&self) -> Vec<HtmlAttribution> {
+        self.author_attributions
+            .iter()
+            .flatten()
+            .filter_map(|a| {
+                HtmlAttribution::from_parts(a.display_name.as_deref(), a.uri.as_deref())
+            })
+            .collect()
+    }
+}
+
+impl Attributed for v1::Place {
+    fn attributions(&self) -> Vec<HtmlAttribution> {
+        let mut attributions = self
+            .attributions
+            .iter()
+            .flatten()
+            .filter_map(|a| {
+                HtmlAttribution::from_parts(a.provider.as_deref(), a.provider_uri.as_deref())
+            })
+            .collect::<Vec<_>>();
+        attributions.extend(self.photos.iter().flatten().flat_map(|p| p.attributions()));
+        attributions
+    }
+}
+
+/// Gathers the attributions of every result used to build a view, without duplicates.
+#[derive(Debug, Clone, Default)]
+pub struct AttributionCollector {
+    attributions: Vec<HtmlAttribution>,
+    seen: HashSet<HtmlAttribution>,
+}
+
+impl AttributionCollector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the attributions of a result, e.g. a `NearbySearchResult` or a `Photo`.
+    pub fn add(&mut self, source: &impl Attributed) -> &mut AttributionCollector {
+        for attribution in source.attributions() {
+            self.add_attribution(attribution);
+        }
+        self
+    }
+
+    /// Adds a raw html attribution.
+    pub fn add_html(&mut self, html: &str) -> &mut AttributionCollector {
+        self.add_attribution(HtmlAttribution::parse(html));
+        self
+    }
+
+    fn add_attribution(&mut self, attribution: HtmlAttribution) {
+        if !attribution.segments.is_empty() && self.seen.insert(attribution.clone()) {
+            self.attributions.push(attribution);
+        }
+    }
+
+    /// The collected attributions, in the order they were first added.
+    pub fn attributions(&self) -> &[HtmlAttribution] {
+        &self.attributions
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.attributions.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.attributions.len()
+    }
+
+    /// Renders the attributions as plain text, one per line.
+    pub fn to_plain_text(&self) -> String {
+        self.render(HtmlAttribution::to_plain_text, "\n")
+    }
+
+    /// Renders the attributions as Markdown links, separated by commas.
+    pub fn to_markdown(&self) -> String {
+        self.render(HtmlAttribution::to_markdown, ", ")
+    }
+
+    /// Renders the attributions as sanitized html links, separated by commas.
+    pub fn to_html(&self) -> String {
+        self.render(HtmlAttribution::to_html, ", ")
+    }
+
+    fn render(&self, render: fn(&HtmlAttribution) -> String, separator: &str) -> String {
+        self.attributions
+            .iter()
+            .map(render)
+            .collect::<Vec<_>>()
+            .join(separator)
+    }
+}
+
+fn anchor_href(html: &str) -> Option<String> {
+    let lower = html.to_ascii_lowercase();
+    let anchor = lower.find("<a ")?;
+    let tag_end = anchor + lower[anchor..].find('>')?;
+    let attribute = anchor + lower[anchor..tag_end].find("href=")? + "href=".len();
+
+    let value = &html[attribute..tag_end];
+    match value.chars().next()? {
+        quote @ ('"' | '\'') => value[1..].split(quote).next().map(String::from),
+        _ => value.split_whitespace().next().map(String::from),
+    }
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(
+            c,
+            '\\' | '[' | ']' | '(' | ')' | '*' | '_' | '`' | '<' | '>' | '#'
+        ) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AttributionCollector, AttributionSegment, HtmlAttribution};
+    use crate::types::{NearbySearchResult, Photo};
+
+    fn segment(text: &str, href: Option<&str>) -> AttributionSegment {
+        AttributionSegment {
+            text: String::from(text),
+            href: href.map(String::from),
+        }
+    }
+
+    #[test]
+    fn test_parse_anchor() {
+        let attribution = HtmlAttribution::parse(
+            r#"<a href="https://maps.google.com/maps/contrib/110751364053842618118">Tom &amp; Jerry</a>"#,
+        );
+        assert_eq!(
+            attribution.segments,
+            vec![segment(
+                "Tom & Jerry",
+                Some("https://maps.google.com/maps/contrib/110751364053842618118")
+            )]
+        );
+    }
+
+    #[test]
+    fn test_parse_text_around_anchors() {
+        let attribution = HtmlAttribution::parse(
+            r#"Photos by <a href="https://example.com/a">A</a> and <A HREF='https://example.com/b'>B</A>."#,
+        );
+        assert_eq!(
+            attribution.segments,
+            vec![
+                segment("Photos by ", None),
+                segment("A", Some("https://example.com/a")),
+                segment(" and ", None),
+                segment("B", Some("https://example.com/b")),
+                segment(".", None),
+            ]
+        );
+        assert_eq!(attribution.text(), "Photos by A and B.");
+    }
+
+    #[test]
+    fn test_parse_plain_text() {
+        let attribution = HtmlAttribution::parse("Listings by <b>Example</b>");
+        assert_eq!(
+            attribution.segments,
+            vec![segment("Listings by Example", None)]
+        );
+    }
+
+    #[test]
+    fn test_collector_dedupes_and_renders() {
+        let photo = |html: &str| Photo {
+            id: String::from("reference"),
+            height: 1,
+            width: 1,
+            html_attributions: vec![String::from(html)],
+        };
+        let result: NearbySearchResult = serde_json::from_value(serde_json::json!({
+            "html_attributions": ["Listings by <a href=\"https://example.com\">Example</a>"],
+            "results": [],
+            "status": "OK"
+        }))
+        .unwrap();
+
+        let mut collector = AttributionCollector::new();
+        collector
+            .add(&result)
+            .add(&photo(
+                r#"<a href="https://maps.google.com/maps/contrib/1">Tom &amp; Jerry</a>"#,
+            ))
+            .add(&photo(
+                r#"<a href="https://maps.google.com/maps/contrib/1">Tom &amp; Jerry</a>"#,
+            ))
+            .add_html(r#"<a href="javascript:alert(1)"><script>x</script>Evil</a>"#);
+
+        assert_eq!(collector.len(), 3);
+        assert_eq!(
+            collector.to_plain_text(),
+            "Listings by Example (https://example.com)\nTom & Jerry (https://maps.google.com/maps/contrib/1)\nxEvil"
+        );
+        assert_eq!(
+            collector.to_markdown(),
+            "Listings by [Example](<https://example.com>), [Tom & Jerry](<https://maps.google.com/maps/contrib/1>), xEvil"
+        );
+        assert_eq!(
+            collector.to_html(),
+            concat!(
+                r#"Listings by <a href="https://example.com" target="_blank" rel="noopener noreferrer">Example</a>, "#,
+                r#"<a href="https://maps.google.com/maps/contrib/1" target="_blank" rel="noopener noreferrer">Tom &amp; Jerry</a>, "#,
+                "xEvil"
+            )
+        );
+    }
+}
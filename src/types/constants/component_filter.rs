@@ -0,0 +1,44 @@
+use isocountry::CountryCode;
+use std::fmt;
+
+/// A component filter of a Geocoding request, restricting the results to a specific area.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ComponentFilter {
+    Route(String),
+    Locality(String),
+    AdministrativeArea(String),
+    PostalCode(String),
+    Country(CountryCode),
+}
+
+impl fmt::Display for ComponentFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ComponentFilter::Route(route) => write!(f, "route:{}", route),
+            ComponentFilter::Locality(locality) => write!(f, "locality:{}", locality),
+            ComponentFilter::AdministrativeArea(area) => {
+                write!(f, "administrative_area:{}", area)
+            }
+            ComponentFilter::PostalCode(postal_code) => write!(f, "postal_code:{}", postal_code),
+            ComponentFilter::Country(country) => write!(f, "country:{}", country.alpha2()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::constants::ComponentFilter;
+    use isocountry::CountryCode;
+
+    #[test]
+    fn test_component_filter_as_str() {
+        assert_eq!(
+            ComponentFilter::PostalCode(String::from("94043")).to_string(),
+            "postal_code:94043"
+        );
+        assert_eq!(
+            ComponentFilter::Country(CountryCode::DEU).to_string(),
+            "country:DE"
+        );
+    }
+}
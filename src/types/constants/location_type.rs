@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+
+/// The precision of a geocoded location.
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Hash, PartialEq, Eq, Display, EnumString)]
+pub enum LocationType {
+    /// A precise geocode down to the street address.
+    #[serde(rename = "ROOFTOP")]
+    #[strum(serialize = "ROOFTOP")]
+    Rooftop,
+    /// An approximation interpolated between two precise points, e.g. intersections.
+    #[serde(rename = "RANGE_INTERPOLATED")]
+    #[strum(serialize = "RANGE_INTERPOLATED")]
+    RangeInterpolated,
+    /// The geometric center of a polyline (a street) or polygon (a region).
+    #[serde(rename = "GEOMETRIC_CENTER")]
+    #[strum(serialize = "GEOMETRIC_CENTER")]
+    GeometricCenter,
+    /// An approximate location.
+    #[serde(rename = "APPROXIMATE")]
+    #[strum(serialize = "APPROXIMATE")]
+    Approximate,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::constants::LocationType;
+
+    #[test]
+    fn test_location_type_as_str() {
+        assert_eq!(LocationType::Rooftop.to_string(), "ROOFTOP");
+        assert_eq!(
+            LocationType::RangeInterpolated.to_string(),
+            "RANGE_INTERPOLATED"
+        );
+    }
+
+    #[test]
+    fn test_location_type_parse() {
+        let parsed_result: LocationType = "GEOMETRIC_CENTER".parse().unwrap();
+        assert_eq!(parsed_result, LocationType::GeometricCenter);
+        let parsed_result: LocationType = serde_json::from_str("\"APPROXIMATE\"").unwrap();
+        assert_eq!(parsed_result, LocationType::Approximate);
+    }
+}
@@ -1,17 +1,27 @@
+pub mod component_filter;
 pub mod input_type;
 pub mod language;
 pub mod location_bias;
+pub mod location_type;
+pub mod opening_hours_type;
 pub mod place;
 pub mod place_data_fields;
 pub mod place_types;
 pub mod rank_by;
 pub mod review_sort;
+pub mod time_of_day;
+pub mod weekday;
 
+pub use component_filter::ComponentFilter;
 pub use input_type::InputType;
 pub use language::Language;
 pub use location_bias::LocationBias;
+pub use location_type::LocationType;
+pub use opening_hours_type::OpeningHoursType;
 pub use place::{PlaceDetailsPlace, PlaceSearchPlace};
 pub use place_data_fields::{PlaceDetailsPlaceFields, PlaceSearchPlaceFields};
 pub use place_types::PlaceTypes;
 pub use rank_by::RankBy;
 pub use review_sort::ReviewSort;
+pub use time_of_day::{InvalidTimeOfDay, TimeOfDay};
+pub use weekday::Weekday;
@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+
+/// The service described by secondary opening hours, e.g. the kitchen of a restaurant.
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Hash, PartialEq, Eq, Display, EnumString)]
+pub enum OpeningHoursType {
+    #[serde(rename = "ACCESS")]
+    #[strum(serialize = "ACCESS")]
+    Access,
+    #[serde(rename = "BREAKFAST")]
+    #[strum(serialize = "BREAKFAST")]
+    Breakfast,
+    #[serde(rename = "BRUNCH")]
+    #[strum(serialize = "BRUNCH")]
+    Brunch,
+    #[serde(rename = "DELIVERY")]
+    #[strum(serialize = "DELIVERY")]
+    Delivery,
+    #[serde(rename = "DINNER")]
+    #[strum(serialize = "DINNER")]
+    Dinner,
+    #[serde(rename = "DRIVE_THROUGH")]
+    #[strum(serialize = "DRIVE_THROUGH")]
+    DriveThrough,
+    #[serde(rename = "HAPPY_HOUR")]
+    #[strum(serialize = "HAPPY_HOUR")]
+    HappyHour,
+    #[serde(rename = "KITCHEN")]
+    #[strum(serialize = "KITCHEN")]
+    Kitchen,
+    #[serde(rename = "LUNCH")]
+    #[strum(serialize = "LUNCH")]
+    Lunch,
+    #[serde(rename = "ONLINE_SERVICE_HOURS")]
+    #[strum(serialize = "ONLINE_SERVICE_HOURS")]
+    OnlineServiceHours,
+    #[serde(rename = "PICKUP")]
+    #[strum(serialize = "PICKUP")]
+    Pickup,
+    #[serde(rename = "SENIOR_HOURS")]
+    #[strum(serialize = "SENIOR_HOURS")]
+    SeniorHours,
+    #[serde(rename = "TAKEOUT")]
+    #[strum(serialize = "TAKEOUT")]
+    Takeout,
+    /// A type added by Google after this release.
+    #[serde(rename = "UNKNOWN", other)]
+    #[strum(serialize = "UNKNOWN")]
+    Unknown,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::constants::OpeningHoursType;
+
+    #[test]
+    fn test_opening_hours_type_parse() {
+        let parsed: Vec<OpeningHoursType> =
+            serde_json::from_str(r#"["DRIVE_THROUGH", "KITCHEN", "SECRET_MENU"]"#).unwrap();
+        assert_eq!(
+            parsed,
+            vec![
+                OpeningHoursType::DriveThrough,
+                OpeningHoursType::Kitchen,
+                OpeningHoursType::Unknown,
+            ]
+        );
+        assert_eq!(OpeningHoursType::HappyHour.to_string(), "HAPPY_HOUR");
+        let parsed_result: OpeningHoursType = "SENIOR_HOURS".parse().unwrap();
+        assert_eq!(parsed_result, OpeningHoursType::SeniorHours);
+    }
+}
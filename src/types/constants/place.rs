@@ -1,5 +1,7 @@
+use crate::types::constants::{LocationType, OpeningHoursType, PlaceTypes, TimeOfDay, Weekday};
+use crate::types::v1::{BusinessStatus, PriceLevel};
 use crate::types::Photo;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::{json, Value};
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
@@ -10,7 +12,7 @@ pub struct PlaceDetailsPlace {
     pub name: Option<String>,
     pub address_components: Option<Vec<AddressComponent>>,
     pub adr_address: Option<String>,
-    pub business_status: Option<String>,
+    pub business_status: Option<BusinessStatus>,
     pub formatted_address: Option<String>,
     pub geometry: Option<Geometry>,
     pub icon: Option<String>,
@@ -18,7 +20,7 @@ pub struct PlaceDetailsPlace {
     pub icon_background_color: Option<String>,
     pub photos: Option<Vec<Photo>>,
     pub plus_code: Option<PlusCode>,
-    pub types: Option<Vec<String>>,
+    pub types: Option<Vec<PlaceTypes>>,
     pub url: Option<String>,
     pub utc_offset: Option<i32>,
     pub vicinity: Option<String>,
@@ -37,7 +39,8 @@ pub struct PlaceDetailsPlace {
     pub delivery: Option<bool>,
     pub dine_in: Option<bool>,
     pub editorial_summary: Option<PlaceEditorialSummary>,
-    pub price_level: Option<i32>,
+    #[serde(default, with = "legacy_price_level")]
+    pub price_level: Option<PriceLevel>,
     pub rating: Option<f32>,
     pub reservable: Option<bool>,
     pub reviews: Option<Vec<Review>>,
@@ -58,7 +61,7 @@ pub struct PlaceSearchPlace {
     #[serde(rename = "place_id")]
     pub id: String,
     pub name: Option<String>,
-    pub business_status: Option<String>,
+    pub business_status: Option<BusinessStatus>,
     pub formatted_address: Option<String>,
     pub geometry: Option<Geometry>,
     pub icon: Option<String>,
@@ -66,18 +69,44 @@ pub struct PlaceSearchPlace {
     pub icon_background_color: Option<String>,
     pub photos: Option<Vec<Photo>>,
     pub plus_code: Option<PlusCode>,
-    pub types: Option<Vec<String>>,
+    pub types: Option<Vec<PlaceTypes>>,
     pub vicinity: Option<String>,
 
     // Contact
     pub opening_hours: Option<OpeningHours>,
 
     // Atmosphere
-    pub price_level: Option<i32>,
+    #[serde(default, with = "legacy_price_level")]
+    pub price_level: Option<PriceLevel>,
     pub rating: Option<f32>,
     pub user_ratings_total: Option<i32>,
 }
 
+/// The legacy endpoints send the price level as an integer from 0 to 4, an unknown level
+/// is written back as the integer it was read from.
+mod legacy_price_level {
+    use super::{Deserialize, Deserializer, PriceLevel, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        price_level: &Option<PriceLevel>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        price_level
+            .as_ref()
+            .and_then(|price_level| match price_level {
+                PriceLevel::Unknown(level) => level.parse::<i64>().ok(),
+                price_level => price_level.level().map(i64::from),
+            })
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<PriceLevel>, D::Error> {
+        Ok(Option::<i64>::deserialize(deserializer)?.map(PriceLevel::from_level))
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AddressComponent {
     pub long_name: Option<String>,
@@ -89,11 +118,16 @@ pub struct AddressComponent {
 pub struct Geometry {
     pub location: Option<Location>,
     pub viewport: Option<Viewport>,
+    /// The precision of the location, only returned by the Geocoding API.
+    pub location_type: Option<LocationType>,
+    /// The bounding box of the whole result, only returned by the Geocoding API.
+    pub bounds: Option<Viewport>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Location {
     pub lat: Option<f64>,
+    #[serde(rename = "lng", alias = "lon")]
     pub lon: Option<f64>,
 }
 
@@ -114,7 +148,7 @@ impl Location {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Viewport {
     pub northeast: Option<Location>,
     pub southwest: Option<Location>,
@@ -135,8 +169,39 @@ pub struct Period {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DayTime {
-    pub day: Option<i32>,
-    pub time: Option<String>,
+    pub day: Option<WireValue<Weekday>>,
+    pub time: Option<WireValue<TimeOfDay>>,
+}
+
+impl DayTime {
+    /// The day, `None` if missing or out of range.
+    pub fn weekday(&self) -> Option<Weekday> {
+        self.day.as_ref()?.valid().copied()
+    }
+
+    /// The time, `None` if missing or not `"HHMM"`.
+    pub fn time_of_day(&self) -> Option<TimeOfDay> {
+        self.time.as_ref()?.valid().copied()
+    }
+}
+
+/// A wire value kept as sent when it does not parse, so that one bad value neither fails
+/// the whole response nor is lost when serializing again.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum WireValue<T> {
+    Valid(T),
+    Invalid(Value),
+}
+
+impl<T> WireValue<T> {
+    /// The parsed value, `None` if it was invalid.
+    pub fn valid(&self) -> Option<&T> {
+        match self {
+            WireValue::Valid(value) => Some(value),
+            WireValue::Invalid(_) => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -166,8 +231,9 @@ pub struct PlaceOpeningHours {
     pub open_now: Option<bool>,
     pub periods: Option<Vec<OpeningHoursPeriod>>,
     pub special_days: Option<Vec<PlaceSpecialDay>>,
+    /// The service these hours apply to, only set in `secondary_opening_hours`.
     #[serde(rename = "type")]
-    pub place_opening_hours_type: Option<String>,
+    pub place_opening_hours_type: Option<OpeningHoursType>,
     pub weekday_text: Option<Vec<String>>,
 }
 
@@ -185,8 +251,24 @@ pub struct OpeningHoursPeriod {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OpeningHoursTime {
-    pub day: Option<i32>,
-    pub time: Option<String>,
+    pub day: Option<WireValue<Weekday>>,
+    pub time: Option<WireValue<TimeOfDay>>,
+    /// The local date, `YYYY-MM-DD`, only set in `current_opening_hours`.
+    pub date: Option<String>,
+    /// Set when the period extends beyond the 7 days covered by `current_opening_hours`.
+    pub truncated: Option<bool>,
+}
+
+impl OpeningHoursTime {
+    /// The day, `None` if missing or out of range.
+    pub fn weekday(&self) -> Option<Weekday> {
+        self.day.as_ref()?.valid().copied()
+    }
+
+    /// The time, `None` if missing or not `"HHMM"`.
+    pub fn time_of_day(&self) -> Option<TimeOfDay> {
+        self.time.as_ref()?.valid().copied()
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -215,7 +297,7 @@ impl PlaceSearchPlace {
     }
 }
 
-fn remove_empty_fields(value: &Value) -> Value {
+pub(crate) fn remove_empty_fields(value: &Value) -> Value {
     match value {
         Value::Object(obj) => {
             let cleaned_fields: serde_json::Map<String, Value> = obj
@@ -234,3 +316,62 @@ fn remove_empty_fields(value: &Value) -> Value {
         _ => value.clone(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::types::constants::place::{OpeningHours, WireValue};
+    use crate::types::constants::{PlaceDetailsPlace, PlaceSearchPlace, Weekday};
+    use crate::types::v1::{BusinessStatus, PriceLevel};
+
+    #[test]
+    fn test_unknown_business_status_and_price_level_round_trip() {
+        let json = serde_json::json!({
+            "place_id": "ChIJN1t_tDeuEmsRUsoyG83frY4",
+            "business_status": "CLOSED_FOREVER",
+            "price_level": 7
+        });
+
+        let place: PlaceSearchPlace = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(
+            place.business_status,
+            Some(BusinessStatus::Unknown(String::from("CLOSED_FOREVER")))
+        );
+        assert_eq!(
+            place.price_level,
+            Some(PriceLevel::Unknown(String::from("7")))
+        );
+        let written = serde_json::to_value(&place).unwrap();
+        assert_eq!(written["business_status"], json["business_status"]);
+        assert_eq!(written["price_level"], json["price_level"]);
+
+        let place: PlaceDetailsPlace = serde_json::from_value(json.clone()).unwrap();
+        let written = serde_json::to_value(&place).unwrap();
+        assert_eq!(written["business_status"], json["business_status"]);
+        assert_eq!(written["price_level"], json["price_level"]);
+    }
+
+    #[test]
+    fn test_invalid_day_and_time_round_trip() {
+        let json = serde_json::json!({
+            "periods": [
+                { "open": { "day": 7, "time": "0930" }, "close": { "day": 1, "time": "9:30" } }
+            ]
+        });
+
+        let hours: OpeningHours = serde_json::from_value(json.clone()).unwrap();
+        let period = &hours.periods.as_ref().unwrap()[0];
+        let open = period.open.as_ref().unwrap();
+        assert_eq!(open.day, Some(WireValue::Invalid(serde_json::json!(7))));
+        assert_eq!(open.weekday(), None);
+        assert_eq!(
+            open.time_of_day().map(|t| t.to_string()).as_deref(),
+            Some("0930")
+        );
+        let close = period.close.as_ref().unwrap();
+        assert_eq!(close.weekday(), Some(Weekday::Monday));
+        assert_eq!(close.time_of_day(), None);
+
+        let written = serde_json::to_value(&hours).unwrap();
+        assert_eq!(written["periods"], json["periods"]);
+    }
+}
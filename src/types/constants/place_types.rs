@@ -1,7 +1,7 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use strum_macros::{Display, EnumString};
 
-#[derive(Debug, Copy, Clone, Serialize, Deserialize, Hash, PartialEq, Eq, Display, EnumString)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Display, EnumString)]
 pub enum PlaceTypes {
     //Table 1 lists the types that are supported for place searches,
     //and can be returned with Place details results, and as part of autocomplete place predictions.
@@ -298,6 +298,24 @@ pub enum PlaceTypes {
     Regions,
     #[strum(serialize = "(cities)")]
     Cities,
+
+    /// A type added by Google after this release.
+    #[strum(default)]
+    Unknown(String),
+}
+
+/// Place types are written as on the wire, e.g. `"amusement_park"`.
+impl Serialize for PlaceTypes {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PlaceTypes {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(value.parse().unwrap_or(PlaceTypes::Unknown(value)))
+    }
 }
 
 mod tests {
@@ -319,4 +337,21 @@ mod tests {
         let parsed_result: PlaceTypes = "amusement_park".parse().unwrap();
         assert_eq!(parsed_result, PlaceTypes::AmusementPark);
     }
+
+    #[test]
+    fn test_unknown_place_type() {
+        let parsed_result: Vec<PlaceTypes> =
+            serde_json::from_str(r#"["amusement_park", "brand_new_type"]"#).unwrap();
+        assert_eq!(
+            parsed_result,
+            vec![
+                PlaceTypes::AmusementPark,
+                PlaceTypes::Unknown(String::from("brand_new_type"))
+            ]
+        );
+        assert_eq!(
+            serde_json::to_string(&parsed_result).unwrap(),
+            r#"["amusement_park","brand_new_type"]"#
+        );
+    }
 }
@@ -0,0 +1,142 @@
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+/// A local time of day, written `"HHMM"` on the wire.
+///
+/// `2400` is accepted as the end of a day, since some places close at midnight that way.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TimeOfDay {
+    minutes: u16,
+}
+
+impl TimeOfDay {
+    pub const MIDNIGHT: TimeOfDay = TimeOfDay { minutes: 0 };
+    /// `2400`, the midnight ending a day.
+    pub const END_OF_DAY: TimeOfDay = TimeOfDay { minutes: 24 * 60 };
+
+    /// Returns `None` unless the time is between `00:00` and `24:00`.
+    pub fn new(hour: u8, minute: u8) -> Option<Self> {
+        if hour > 24 || minute > 59 || (hour == 24 && minute != 0) {
+            return None;
+        }
+        Some(Self {
+            minutes: hour as u16 * 60 + minute as u16,
+        })
+    }
+
+    pub fn hour(&self) -> u8 {
+        (self.minutes / 60) as u8
+    }
+
+    pub fn minute(&self) -> u8 {
+        (self.minutes % 60) as u8
+    }
+
+    /// The minutes since midnight, up to 1440 for `2400`.
+    pub fn minutes_since_midnight(&self) -> u32 {
+        self.minutes as u32
+    }
+
+    /// The time as a `chrono::NaiveTime`, `None` for `2400` which it cannot represent.
+    #[cfg(feature = "chrono")]
+    pub fn naive_time(&self) -> Option<chrono::NaiveTime> {
+        chrono::NaiveTime::from_hms_opt(self.hour() as u32, self.minute() as u32, 0)
+    }
+}
+
+/// A time of day that is not a valid `"HHMM"` value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidTimeOfDay(pub String);
+
+impl fmt::Display for InvalidTimeOfDay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid time of day {:?}, expected HHMM", self.0)
+    }
+}
+
+impl std::error::Error for InvalidTimeOfDay {}
+
+impl FromStr for TimeOfDay {
+    type Err = InvalidTimeOfDay;
+
+    fn from_str(time: &str) -> Result<Self, Self::Err> {
+        let invalid = || InvalidTimeOfDay(String::from(time));
+        if time.len() != 4 || !time.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(invalid());
+        }
+        let hour = time[..2].parse().map_err(|_| invalid())?;
+        let minute = time[2..].parse().map_err(|_| invalid())?;
+        TimeOfDay::new(hour, minute).ok_or_else(invalid)
+    }
+}
+
+impl fmt::Display for TimeOfDay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}{:02}", self.hour(), self.minute())
+    }
+}
+
+impl Serialize for TimeOfDay {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TimeOfDay {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(D::Error::custom)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::NaiveTime> for TimeOfDay {
+    /// Truncates the seconds.
+    fn from(time: chrono::NaiveTime) -> Self {
+        use chrono::Timelike;
+        Self {
+            minutes: (time.hour() * 60 + time.minute()) as u16,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::constants::TimeOfDay;
+
+    #[test]
+    fn test_time_of_day_parse() {
+        let time: TimeOfDay = "0930".parse().unwrap();
+        assert_eq!((time.hour(), time.minute()), (9, 30));
+        assert_eq!(time.minutes_since_midnight(), 570);
+        assert_eq!("2400".parse::<TimeOfDay>(), Ok(TimeOfDay::END_OF_DAY));
+        assert!("9:30".parse::<TimeOfDay>().is_err());
+        assert!("2460".parse::<TimeOfDay>().is_err());
+        assert!("2401".parse::<TimeOfDay>().is_err());
+    }
+
+    #[test]
+    fn test_time_of_day_wire_format() {
+        let times: Vec<TimeOfDay> = serde_json::from_str(r#"["0000", "0930", "2400"]"#).unwrap();
+        assert_eq!(
+            serde_json::to_string(&times).unwrap(),
+            r#"["0000","0930","2400"]"#
+        );
+        assert!(serde_json::from_str::<TimeOfDay>(r#""930""#).is_err());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_chrono_time() {
+        let time = chrono::NaiveTime::from_hms_opt(9, 30, 15).unwrap();
+        assert_eq!(TimeOfDay::from(time), TimeOfDay::new(9, 30).unwrap());
+        assert_eq!(
+            TimeOfDay::new(9, 30).unwrap().naive_time(),
+            chrono::NaiveTime::from_hms_opt(9, 30, 0)
+        );
+        assert_eq!(TimeOfDay::END_OF_DAY.naive_time(), None);
+    }
+}
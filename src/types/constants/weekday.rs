@@ -0,0 +1,127 @@
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+
+/// A day of the week, numbered from 0 for Sunday as in the legacy opening hours.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Weekday {
+    Sunday,
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+}
+
+impl Weekday {
+    /// Every day of the week, starting on Sunday.
+    pub const ALL: [Weekday; 7] = [
+        Weekday::Sunday,
+        Weekday::Monday,
+        Weekday::Tuesday,
+        Weekday::Wednesday,
+        Weekday::Thursday,
+        Weekday::Friday,
+        Weekday::Saturday,
+    ];
+
+    /// The day of a wire `day` number, 0 being Sunday.
+    pub fn from_index(index: i64) -> Option<Self> {
+        usize::try_from(index)
+            .ok()
+            .and_then(|index| Weekday::ALL.get(index))
+            .copied()
+    }
+
+    /// The wire `day` number, i.e. the days since Sunday.
+    pub fn index(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn succ(&self) -> Self {
+        Weekday::ALL[(self.index() as usize + 1) % 7]
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            Weekday::Sunday => "Sunday",
+            Weekday::Monday => "Monday",
+            Weekday::Tuesday => "Tuesday",
+            Weekday::Wednesday => "Wednesday",
+            Weekday::Thursday => "Thursday",
+            Weekday::Friday => "Friday",
+            Weekday::Saturday => "Saturday",
+        }
+    }
+}
+
+impl fmt::Display for Weekday {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for Weekday {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(self.index())
+    }
+}
+
+impl<'de> Deserialize<'de> for Weekday {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let index = i64::deserialize(deserializer)?;
+        Weekday::from_index(index).ok_or_else(|| {
+            D::Error::custom(format!(
+                "invalid day {}, expected 0 (Sunday) to 6 (Saturday)",
+                index
+            ))
+        })
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<Weekday> for chrono::Weekday {
+    fn from(day: Weekday) -> Self {
+        match day {
+            Weekday::Sunday => chrono::Weekday::Sun,
+            Weekday::Monday => chrono::Weekday::Mon,
+            Weekday::Tuesday => chrono::Weekday::Tue,
+            Weekday::Wednesday => chrono::Weekday::Wed,
+            Weekday::Thursday => chrono::Weekday::Thu,
+            Weekday::Friday => chrono::Weekday::Fri,
+            Weekday::Saturday => chrono::Weekday::Sat,
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::Weekday> for Weekday {
+    fn from(day: chrono::Weekday) -> Self {
+        Weekday::ALL[day.num_days_from_sunday() as usize]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::constants::Weekday;
+
+    #[test]
+    fn test_weekday_wire_format() {
+        let days: Vec<Weekday> = serde_json::from_str("[0, 1, 6]").unwrap();
+        assert_eq!(
+            days,
+            vec![Weekday::Sunday, Weekday::Monday, Weekday::Saturday]
+        );
+        assert_eq!(serde_json::to_string(&days).unwrap(), "[0,1,6]");
+        assert!(serde_json::from_str::<Weekday>("7").is_err());
+        assert_eq!(Weekday::Saturday.succ(), Weekday::Sunday);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_chrono_weekday() {
+        assert_eq!(chrono::Weekday::from(Weekday::Sunday), chrono::Weekday::Sun);
+        assert_eq!(Weekday::from(chrono::Weekday::Mon), Weekday::Monday);
+    }
+}
@@ -0,0 +1,492 @@
+use crate::types::constants::place::{Location, Viewport};
+
+/// The mean radius of the Earth, used by the spherical formulas.
+pub const EARTH_RADIUS_METERS: f64 = 6_371_008.8;
+
+/// The semi-major axis and flattening of the WGS 84 ellipsoid, used by Vincenty's formula.
+const WGS84_A: f64 = 6_378_137.0;
+const WGS84_F: f64 = 1.0 / 298.257_223_563;
+
+impl Location {
+    /// The `(lat, lng)` pair, if both are set.
+    pub fn coordinates(&self) -> Option<(f64, f64)> {
+        Some((self.lat?, self.lon?))
+    }
+
+    /// The great-circle distance in meters to another location, with the haversine formula.
+    pub fn distance_to(&self, other: &Location) -> Option<f64> {
+        let (lat1, lon1) = self.coordinates()?;
+        let (lat2, lon2) = other.coordinates()?;
+        let (phi1, phi2) = (lat1.to_radians(), lat2.to_radians());
+        let d_phi = (lat2 - lat1).to_radians();
+        let d_lambda = (lon2 - lon1).to_radians();
+
+        let a =
+            (d_phi / 2.0).sin().powi(2) + phi1.cos() * phi2.cos() * (d_lambda / 2.0).sin().powi(2);
+        Some(2.0 * EARTH_RADIUS_METERS * a.sqrt().min(1.0).asin())
+    }
+
+    /// The distance in meters to another location on the WGS 84 ellipsoid, with Vincenty's
+    /// inverse formula. Accurate to the millimeter, but `None` for nearly antipodal points
+    /// where the formula does not converge.
+    pub fn vincenty_distance_to(&self, other: &Location) -> Option<f64> {
+        let (lat1, lon1) = self.coordinates()?;
+        let (lat2, lon2) = other.coordinates()?;
+        let b = WGS84_A * (1.0 - WGS84_F);
+        let l = (lon2 - lon1).to_radians();
+        let (sin_u1, cos_u1) = ((1.0 - WGS84_F) * lat1.to_radians().tan()).atan().sin_cos();
+        let (sin_u2, cos_u2) = ((1.0 - WGS84_F) * lat2.to_radians().tan()).atan().sin_cos();
+
+        let mut lambda = l;
+        for _ in 0..200 {
+            let (sin_lambda, cos_lambda) = lambda.sin_cos();
+            let sin_sigma = ((cos_u2 * sin_lambda).powi(2)
+                + (cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda).powi(2))
+            .sqrt();
+            if sin_sigma == 0.0 {
+                return Some(0.0);
+            }
+            let cos_sigma = sin_u1 * sin_u2 + cos_u1 * cos_u2 * cos_lambda;
+            let sigma = sin_sigma.atan2(cos_sigma);
+            let sin_alpha = cos_u1 * cos_u2 * sin_lambda / sin_sigma;
+            let cos2_alpha = 1.0 - sin_alpha.powi(2);
+            // Both points on the equator.
+            let cos_2sigma_m = if cos2_alpha == 0.0 {
+                0.0
+            } else {
+                cos_sigma - 2.0 * sin_u1 * sin_u2 / cos2_alpha
+            };
+            let c = WGS84_F / 16.0 * cos2_alpha * (4.0 + WGS84_F * (4.0 - 3.0 * cos2_alpha));
+
+            let previous = lambda;
+            lambda = l
+                + (1.0 - c)
+                    * WGS84_F
+                    * sin_alpha
+                    * (sigma
+                        + c * sin_sigma
+                            * (cos_2sigma_m + c * cos_sigma * (2.0 * cos_2sigma_m.powi(2) - 1.0)));
+            if (lambda - previous).abs() < 1e-12 {
+                let u2 = cos2_alpha * (WGS84_A.powi(2) - b.powi(2)) / b.powi(2);
+                let big_a =
+                    1.0 + u2 / 16384.0 * (4096.0 + u2 * (-768.0 + u2 * (320.0 - 175.0 * u2)));
+                let big_b = u2 / 1024.0 * (256.0 + u2 * (-128.0 + u2 * (74.0 - 47.0 * u2)));
+                let delta_sigma = big_b
+                    * sin_sigma
+                    * (cos_2sigma_m
+                        + big_b / 4.0
+                            * (cos_sigma * (2.0 * cos_2sigma_m.powi(2) - 1.0)
+                                - big_b / 6.0
+                                    * cos_2sigma_m
+                                    * (4.0 * sin_sigma.powi(2) - 3.0)
+                                    * (4.0 * cos_2sigma_m.powi(2) - 3.0)));
+                return Some(b * big_a * (sigma - delta_sigma));
+            }
+        }
+        None
+    }
+
+    /// The initial bearing in degrees, clockwise from north in `[0, 360)`, of the
+    /// great-circle path to another location.
+    pub fn bearing_to(&self, other: &Location) -> Option<f64> {
+        let (lat1, lon1) = self.coordinates()?;
+        let (lat2, lon2) = other.coordinates()?;
+        let (phi1, phi2) = (lat1.to_radians(), lat2.to_radians());
+        let d_lambda = (lon2 - lon1).to_radians();
+
+        let y = d_lambda.sin() * phi2.cos();
+        let x = phi1.cos() * phi2.sin() - phi1.sin() * phi2.cos() * d_lambda.cos();
+        Some(y.atan2(x).to_degrees().rem_euclid(360.0))
+    }
+
+    /// The location reached by travelling `distance_meters` along a great circle with an
+    /// initial bearing in degrees.
+    pub fn destination(&self, bearing: f64, distance_meters: f64) -> Option<Location> {
+        let (lat, lon) = self.coordinates()?;
+        let phi1 = lat.to_radians();
+        let theta = bearing.to_radians();
+        let delta = distance_meters / EARTH_RADIUS_METERS;
+
+        let phi2 = (phi1.sin() * delta.cos() + phi1.cos() * delta.sin() * theta.cos()).asin();
+        let lambda =
+            (theta.sin() * delta.sin() * phi1.cos()).atan2(delta.cos() - phi1.sin() * phi2.sin());
+        Some(Location::new(
+            phi2.to_degrees(),
+            wrap_longitude(lon + lambda.to_degrees()),
+        ))
+    }
+}
+
+/// Longitudes in `[-180, 180)`.
+fn wrap_longitude(lng: f64) -> f64 {
+    (lng + 180.0).rem_euclid(360.0) - 180.0
+}
+
+/// A range of longitudes going east from `west`, which may cross the antimeridian.
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct LongitudeArc {
+    west: f64,
+    span: f64,
+}
+
+impl LongitudeArc {
+    fn new(west: f64, east: f64) -> Self {
+        let span = if east - west >= 360.0 {
+            360.0
+        } else {
+            (east - west).rem_euclid(360.0)
+        };
+        Self { west, span }
+    }
+
+    fn offset(&self, lng: f64) -> f64 {
+        (lng - self.west).rem_euclid(360.0)
+    }
+
+    fn contains(&self, lng: f64) -> bool {
+        self.offset(lng) <= self.span
+    }
+
+    fn union(&self, other: &LongitudeArc) -> LongitudeArc {
+        let arc = |west: f64, span: f64| LongitudeArc {
+            west,
+            span: span.min(360.0),
+        };
+        if self.contains(other.west) {
+            arc(
+                self.west,
+                self.span.max(self.offset(other.west) + other.span),
+            )
+        } else if other.contains(self.west) {
+            arc(
+                other.west,
+                other.span.max(other.offset(self.west) + self.span),
+            )
+        } else {
+            // Disjoint arcs are bridged over the smallest gap.
+            let through_east = arc(self.west, self.offset(other.west) + other.span);
+            let through_west = arc(other.west, other.offset(self.west) + self.span);
+            if through_east.span <= through_west.span {
+                through_east
+            } else {
+                through_west
+            }
+        }
+    }
+
+    fn intersection(&self, other: &LongitudeArc) -> Option<LongitudeArc> {
+        if self.contains(other.west) {
+            Some(LongitudeArc {
+                west: other.west,
+                span: other.span.min(self.span - self.offset(other.west)),
+            })
+        } else if other.contains(self.west) {
+            Some(LongitudeArc {
+                west: self.west,
+                span: self.span.min(other.span - other.offset(self.west)),
+            })
+        } else {
+            None
+        }
+    }
+
+    fn east(&self) -> f64 {
+        if self.span >= 360.0 {
+            return 180.0;
+        }
+        match wrap_longitude(self.west + self.span) {
+            east if east == -180.0 && self.span > 0.0 => 180.0,
+            east => east,
+        }
+    }
+}
+
+impl Viewport {
+    pub fn new(southwest: Location, northeast: Location) -> Self {
+        Viewport {
+            northeast: Some(northeast),
+            southwest: Some(southwest),
+        }
+    }
+
+    /// The `(south, north)` latitudes and the longitudes of the viewport.
+    fn bounds(&self) -> Option<(f64, f64, LongitudeArc)> {
+        let (south, west) = self.southwest.as_ref()?.coordinates()?;
+        let (north, east) = self.northeast.as_ref()?.coordinates()?;
+        Some((south, north, LongitudeArc::new(west, east)))
+    }
+
+    fn from_bounds(south: f64, north: f64, longitudes: LongitudeArc) -> Self {
+        let west = if longitudes.span >= 360.0 {
+            -180.0
+        } else {
+            longitudes.west
+        };
+        Viewport::new(
+            Location::new(south, west),
+            Location::new(north, longitudes.east()),
+        )
+    }
+
+    /// Whether the location is within the viewport, which may cross the antimeridian.
+    pub fn contains(&self, location: &Location) -> bool {
+        match (self.bounds(), location.coordinates()) {
+            (Some((south, north, longitudes)), Some((lat, lng))) => {
+                (south..=north).contains(&lat) && longitudes.contains(lng)
+            }
+            _ => false,
+        }
+    }
+
+    pub fn center(&self) -> Option<Location> {
+        let (south, north, longitudes) = self.bounds()?;
+        Some(Location::new(
+            (south + north) / 2.0,
+            wrap_longitude(longitudes.west + longitudes.span / 2.0),
+        ))
+    }
+
+    /// The smallest viewport containing both viewports.
+    pub fn union(&self, other: &Viewport) -> Option<Viewport> {
+        let (south1, north1, longitudes1) = self.bounds()?;
+        let (south2, north2, longitudes2) = other.bounds()?;
+        Some(Viewport::from_bounds(
+            south1.min(south2),
+            north1.max(north2),
+            longitudes1.union(&longitudes2),
+        ))
+    }
+
+    /// The smallest viewport containing this viewport and a location.
+    pub fn expand(&self, location: &Location) -> Option<Viewport> {
+        self.union(&Viewport::new(location.clone(), location.clone()))
+    }
+
+    /// The area covered by both viewports, `None` if they do not overlap.
+    pub fn intersection(&self, other: &Viewport) -> Option<Viewport> {
+        let (south1, north1, longitudes1) = self.bounds()?;
+        let (south2, north2, longitudes2) = other.bounds()?;
+        let (south, north) = (south1.max(south2), north1.min(north2));
+        if south > north {
+            return None;
+        }
+        Some(Viewport::from_bounds(
+            south,
+            north,
+            longitudes1.intersection(&longitudes2)?,
+        ))
+    }
+
+    /// The smallest viewport containing a circle, e.g. the `location` and `radius` of a
+    /// nearby search. Circles covering a pole span every longitude.
+    pub fn from_circle(center: &Location, radius_meters: f64) -> Option<Viewport> {
+        let (lat, lng) = center.coordinates()?;
+        let angle = radius_meters / EARTH_RADIUS_METERS;
+        let (south, north) = (lat - angle.to_degrees(), lat + angle.to_degrees());
+        if south <= -90.0 || north >= 90.0 || angle >= std::f64::consts::FRAC_PI_2 {
+            return Some(Viewport::from_bounds(
+                south.max(-90.0),
+                north.min(90.0),
+                LongitudeArc::new(-180.0, 180.0),
+            ));
+        }
+
+        let half_span = (angle.sin() / lat.to_radians().cos()).asin().to_degrees();
+        Some(Viewport::from_bounds(
+            south,
+            north,
+            LongitudeArc {
+                west: wrap_longitude(lng - half_span),
+                span: 2.0 * half_span,
+            },
+        ))
+    }
+
+    /// The center of the viewport and the radius in meters of the smallest circle around
+    /// that center containing the viewport, e.g. for a location bias.
+    pub fn to_circle(&self) -> Option<(Location, f64)> {
+        let (south, north, longitudes) = self.bounds()?;
+        let center = self.center()?;
+        let radius = [
+            (south, longitudes.west),
+            (south, longitudes.east()),
+            (north, longitudes.west),
+            (north, longitudes.east()),
+        ]
+        .iter()
+        .filter_map(|(lat, lng)| center.distance_to(&Location::new(*lat, *lng)))
+        .fold(0.0, f64::max);
+        Some((center, radius))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EARTH_RADIUS_METERS;
+    use crate::types::constants::place::{Location, Viewport};
+
+    fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+        assert!(
+            (actual - expected).abs() <= tolerance,
+            "{} is not within {} of {}",
+            actual,
+            tolerance,
+            expected
+        );
+    }
+
+    fn degrees(degrees: f64, minutes: f64, seconds: f64) -> f64 {
+        degrees.signum() * (degrees.abs() + minutes / 60.0 + seconds / 3600.0)
+    }
+
+    fn viewport(south: f64, west: f64, north: f64, east: f64) -> Viewport {
+        Viewport::new(Location::new(south, west), Location::new(north, east))
+    }
+
+    #[test]
+    fn test_location_wire_format() {
+        let location: Location = serde_json::from_str(r#"{"lat": 47.37, "lng": 8.54}"#).unwrap();
+        assert_eq!(location.coordinates(), Some((47.37, 8.54)));
+        assert_eq!(
+            serde_json::to_string(&location).unwrap(),
+            r#"{"lat":47.37,"lng":8.54}"#
+        );
+        let legacy: Location = serde_json::from_str(r#"{"lat": 1.0, "lon": 2.0}"#).unwrap();
+        assert_eq!(legacy.coordinates(), Some((1.0, 2.0)));
+    }
+
+    #[test]
+    fn test_distances() {
+        let equator = Location::new(0.0, 0.0);
+        let one_degree = EARTH_RADIUS_METERS.to_radians();
+        assert_close(
+            equator.distance_to(&Location::new(0.0, 1.0)).unwrap(),
+            one_degree,
+            1e-6,
+        );
+
+        // The worked example of Vincenty's paper, Flinders Peak to Buninyong.
+        let flinders_peak = Location::new(
+            degrees(-37.0, 57.0, 3.72030),
+            degrees(144.0, 25.0, 29.52440),
+        );
+        let buninyong = Location::new(
+            degrees(-37.0, 39.0, 10.15610),
+            degrees(143.0, 55.0, 35.38390),
+        );
+        assert_close(
+            flinders_peak.vincenty_distance_to(&buninyong).unwrap(),
+            54_972.271,
+            1e-3,
+        );
+        assert_close(
+            flinders_peak.distance_to(&buninyong).unwrap(),
+            54_972.0,
+            200.0,
+        );
+        assert_eq!(equator.vincenty_distance_to(&equator), Some(0.0));
+        assert_eq!(
+            equator.distance_to(&Location {
+                lat: None,
+                lon: None
+            }),
+            None
+        );
+    }
+
+    #[test]
+    fn test_bearing_and_destination() {
+        let origin = Location::new(0.0, 0.0);
+        assert_close(
+            origin.bearing_to(&Location::new(0.0, 1.0)).unwrap(),
+            90.0,
+            1e-9,
+        );
+        assert_close(
+            origin.bearing_to(&Location::new(-1.0, 0.0)).unwrap(),
+            180.0,
+            1e-9,
+        );
+
+        let zurich = Location::new(47.3769, 8.5417);
+        let paris = Location::new(48.8566, 2.3522);
+        let bearing = zurich.bearing_to(&paris).unwrap();
+        let distance = zurich.distance_to(&paris).unwrap();
+        let (lat, lng) = zurich
+            .destination(bearing, distance)
+            .unwrap()
+            .coordinates()
+            .unwrap();
+        assert_close(lat, 48.8566, 1e-9);
+        assert_close(lng, 2.3522, 1e-9);
+
+        let (_, lng) = Location::new(0.0, 179.5)
+            .destination(90.0, EARTH_RADIUS_METERS.to_radians())
+            .unwrap()
+            .coordinates()
+            .unwrap();
+        assert_close(lng, -179.5, 1e-9);
+    }
+
+    #[test]
+    fn test_viewport_contains_and_center() {
+        let europe = viewport(35.0, -10.0, 60.0, 30.0);
+        assert!(europe.contains(&Location::new(47.3769, 8.5417)));
+        assert!(!europe.contains(&Location::new(40.7128, -74.0060)));
+        assert_eq!(europe.center().unwrap().coordinates(), Some((47.5, 10.0)));
+
+        // Fiji crosses the antimeridian.
+        let fiji = viewport(-21.0, 176.0, -12.0, -178.0);
+        assert!(fiji.contains(&Location::new(-17.0, 179.9)));
+        assert!(fiji.contains(&Location::new(-17.0, -179.0)));
+        assert!(!fiji.contains(&Location::new(-17.0, 170.0)));
+        assert_eq!(fiji.center().unwrap().coordinates(), Some((-16.5, 179.0)));
+    }
+
+    #[test]
+    fn test_viewport_union_and_intersection() {
+        let west = viewport(0.0, 0.0, 10.0, 10.0);
+        let east = viewport(5.0, 5.0, 15.0, 20.0);
+        assert_eq!(west.union(&east), Some(viewport(0.0, 0.0, 15.0, 20.0)));
+        assert_eq!(
+            west.intersection(&east),
+            Some(viewport(5.0, 5.0, 10.0, 10.0))
+        );
+        assert_eq!(west.intersection(&viewport(20.0, 0.0, 30.0, 10.0)), None);
+        assert_eq!(west.intersection(&viewport(0.0, 20.0, 10.0, 30.0)), None);
+
+        // Disjoint viewports are joined over the antimeridian when it is shorter.
+        let tonga = viewport(-22.0, -176.0, -15.0, -173.0);
+        let fiji = viewport(-21.0, 176.0, -12.0, 179.0);
+        assert_eq!(
+            fiji.union(&tonga),
+            Some(viewport(-22.0, 176.0, -12.0, -173.0))
+        );
+        assert_eq!(
+            west.expand(&Location::new(-5.0, 12.0)),
+            Some(viewport(-5.0, 0.0, 10.0, 12.0))
+        );
+    }
+
+    #[test]
+    fn test_circle_and_viewport() {
+        let center = Location::new(47.3769, 8.5417);
+        let bounds = Viewport::from_circle(&center, 1_000.0).unwrap();
+        for bearing in [0.0, 45.0, 90.0, 135.0, 180.0, 225.0, 270.0, 315.0] {
+            let edge = center.destination(bearing, 999.0).unwrap();
+            assert!(bounds.contains(&edge), "{} is outside", bearing);
+        }
+        assert!(!bounds.contains(&center.destination(0.0, 1_001.0).unwrap()));
+
+        let (circle_center, radius) = bounds.to_circle().unwrap();
+        assert_close(circle_center.distance_to(&center).unwrap(), 0.0, 1e-6);
+        assert_close(radius, 1_000.0 * 2f64.sqrt(), 5.0);
+
+        let pole = Viewport::from_circle(&Location::new(89.99, 0.0), 10_000.0).unwrap();
+        assert_eq!(pole.southwest.as_ref().unwrap().lon, Some(-180.0));
+        assert_eq!(
+            pole.northeast.as_ref().unwrap().coordinates(),
+            Some((90.0, 180.0))
+        );
+    }
+}
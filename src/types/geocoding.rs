@@ -0,0 +1,177 @@
+use crate::types::constants::place::{AddressComponent, Geometry, Location, PlusCode};
+use crate::types::constants::PlaceTypes;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct GeocodingResult {
+    #[serde(default)]
+    pub results: Vec<GeocodedAddress>,
+    pub status: GeocodingStatus,
+    pub error_message: Option<String>,
+}
+
+impl GeocodingResult {
+    /// The first, most relevant, result.
+    pub fn first(&self) -> Option<&GeocodedAddress> {
+        self.results.first()
+    }
+
+    pub fn display(&self) -> String {
+        let results = self
+            .results
+            .iter()
+            .map(|r| r.display())
+            .collect::<Vec<String>>()
+            .join(", ");
+        format!(
+            "GeocodingResult {{ results: [{}], status: {}, error_message: {} }}",
+            results,
+            self.status,
+            self.error_message.as_deref().unwrap_or_default()
+        )
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct GeocodedAddress {
+    pub place_id: Option<String>,
+    pub formatted_address: Option<String>,
+    #[serde(default)]
+    pub address_components: Vec<AddressComponent>,
+    pub geometry: Option<Geometry>,
+    pub plus_code: Option<PlusCode>,
+    /// The types of the result, new types being kept as `PlaceTypes::Unknown`.
+    #[serde(default)]
+    pub types: Vec<PlaceTypes>,
+    /// Set when the geocoder could only match part of the requested address.
+    pub partial_match: Option<bool>,
+    pub postcode_localities: Option<Vec<String>>,
+}
+
+impl GeocodedAddress {
+    /// The location of the result.
+    pub fn location(&self) -> Option<&Location> {
+        self.geometry.as_ref().and_then(|g| g.location.as_ref())
+    }
+
+    /// The first address component of the given type, e.g. `PlaceTypes::Locality`.
+    pub fn component(&self, place_type: PlaceTypes) -> Option<&AddressComponent> {
+        self.address_components.iter().find(|c| {
+            c.types
+                .iter()
+                .flatten()
+                .any(|t| *t == place_type.to_string())
+        })
+    }
+
+    pub fn display(&self) -> String {
+        format!(
+            "GeocodedAddress {{ place_id: {}, formatted_address: {}, types: [{}] }}",
+            self.place_id.as_deref().unwrap_or_default(),
+            self.formatted_address.as_deref().unwrap_or_default(),
+            self.types
+                .iter()
+                .map(|t| t.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
+
+#[derive(Debug, Serialize, PartialEq, Eq, Deserialize, Display, EnumString, Clone, Default)]
+pub enum GeocodingStatus {
+    #[serde(rename = "OK")]
+    #[strum(serialize = "OK")]
+    Ok,
+    #[serde(rename = "ZERO_RESULTS")]
+    #[strum(serialize = "ZERO_RESULTS")]
+    ZeroResults,
+    #[serde(rename = "OVER_DAILY_LIMIT")]
+    #[strum(serialize = "OVER_DAILY_LIMIT")]
+    OverDailyLimit,
+    #[serde(rename = "OVER_QUERY_LIMIT")]
+    #[strum(serialize = "OVER_QUERY_LIMIT")]
+    OverQueryLimit,
+    #[serde(rename = "REQUEST_DENIED")]
+    #[strum(serialize = "REQUEST_DENIED")]
+    RequestDenied,
+    #[serde(rename = "INVALID_REQUEST")]
+    #[strum(serialize = "INVALID_REQUEST")]
+    InvalidRequest,
+    #[serde(rename = "UNKNOWN_ERROR")]
+    #[strum(serialize = "UNKNOWN_ERROR")]
+    #[default]
+    UnknownError,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GeocodingResult, GeocodingStatus};
+    use crate::types::constants::{LocationType, PlaceTypes};
+
+    #[test]
+    fn test_geocoding_deserialize() {
+        let result: GeocodingResult = serde_json::from_str(
+            r#"{
+                "results": [
+                    {
+                        "address_components": [
+                            { "long_name": "Zürich", "short_name": "Zürich", "types": ["locality", "political"] },
+                            { "long_name": "Switzerland", "short_name": "CH", "types": ["country", "political"] }
+                        ],
+                        "formatted_address": "Zürich, Switzerland",
+                        "geometry": {
+                            "bounds": {
+                                "northeast": { "lat": 47.434666, "lng": 8.625452 },
+                                "southwest": { "lat": 47.320258, "lng": 8.448083 }
+                            },
+                            "location": { "lat": 47.3768866, "lng": 8.541694 },
+                            "location_type": "APPROXIMATE",
+                            "viewport": {
+                                "northeast": { "lat": 47.434666, "lng": 8.625452 },
+                                "southwest": { "lat": 47.320258, "lng": 8.448083 }
+                            }
+                        },
+                        "place_id": "ChIJGaK-SZcLkEcRA9wf5_GNbuY",
+                        "types": ["locality", "political"]
+                    }
+                ],
+                "status": "OK"
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(result.status, GeocodingStatus::Ok);
+        let address = result.first().unwrap();
+        assert_eq!(
+            address.types,
+            vec![PlaceTypes::Locality, PlaceTypes::Political]
+        );
+        assert_eq!(address.location().unwrap().lat, Some(47.3768866));
+        assert_eq!(address.location().unwrap().lon, Some(8.541694));
+        assert_eq!(
+            address.geometry.as_ref().unwrap().location_type,
+            Some(LocationType::Approximate)
+        );
+        assert_eq!(
+            address
+                .component(PlaceTypes::Country)
+                .unwrap()
+                .short_name
+                .as_deref(),
+            Some("CH")
+        );
+    }
+
+    #[test]
+    fn test_geocoding_error_deserialize() {
+        let result: GeocodingResult = serde_json::from_str(
+            r#"{ "results": [], "status": "REQUEST_DENIED", "error_message": "The provided API key is invalid." }"#,
+        )
+        .unwrap();
+
+        assert_eq!(result.status, GeocodingStatus::RequestDenied);
+        assert!(result.first().is_none());
+    }
+}
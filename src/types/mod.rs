@@ -1,8 +1,30 @@
+pub mod attribution;
 pub mod constants;
+pub mod geo;
+pub mod geocoding;
+pub mod opening_hours;
+pub mod photo_data;
+#[cfg(feature = "image")]
+pub mod photo_variant;
+pub mod place;
 pub mod place_details;
+pub mod place_filter;
 pub mod place_photos;
 pub mod place_search;
+pub mod rating_score;
+pub mod v1;
 
+pub use attribution::{Attributed, AttributionCollector, AttributionSegment, HtmlAttribution};
+pub use geocoding::GeocodingResult;
+pub use opening_hours::{OpeningSchedule, WeeklyHours};
+pub use photo_data::{ImageFormat, NotAnImage, PhotoData};
+#[cfg(feature = "image")]
+pub use photo_variant::{Encoding, PhotoVariant, Resize};
+pub use place::Place;
 pub use place_details::PlaceDetailsResult;
-pub use place_photos::Photo;
-pub use place_search::{FindPlaceSearchResult, NearbySearchResult, TextSearchResult};
+pub use place_filter::{FilterablePlace, PlaceFilter, PlaceSort, SortKey, SortOrder};
+pub use place_photos::{Photo, PhotoManifest, PhotoManifestEntry, PhotoUrl};
+pub use place_search::{
+    DistancedPlace, FindPlaceSearchResult, NearbySearchResult, TextSearchResult,
+};
+pub use rating_score::{wilson_lower_bound, BayesianAverage, WILSON_Z_95};
@@ -0,0 +1,678 @@
+use crate::types::constants::place::{
+    DayTime, OpeningHours, OpeningHoursTime, PlaceDetailsPlace, PlaceOpeningHours,
+};
+use crate::types::constants::{OpeningHoursType, TimeOfDay, Weekday};
+use std::ops::Range;
+use std::time::{Duration, SystemTime};
+
+pub const MINUTES_PER_DAY: u32 = 24 * 60;
+pub const MINUTES_PER_WEEK: u32 = 7 * MINUTES_PER_DAY;
+
+/// How far ahead the next opening or closing is searched, a week and a day.
+const SEARCH_HORIZON: i64 = (MINUTES_PER_WEEK + MINUTES_PER_DAY) as i64;
+
+/// An interval of the week, in minutes since Sunday 00:00.
+///
+/// `end` is larger than `start` and exceeds `MINUTES_PER_WEEK` for periods wrapping
+/// from Saturday to Sunday.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct WeekInterval {
+    pub start: u32,
+    pub end: u32,
+}
+
+impl WeekInterval {
+    fn contains(&self, minute_of_week: u32) -> bool {
+        (self.start..self.end).contains(&minute_of_week)
+            || (self.start..self.end).contains(&(minute_of_week + MINUTES_PER_WEEK))
+    }
+}
+
+/// Opening hours normalized to intervals of the week, evaluated in the local time of the place.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WeeklyHours {
+    intervals: Vec<WeekInterval>,
+}
+
+impl WeeklyHours {
+    /// Builds the weekly hours from `(open, close)` minutes of the week.
+    ///
+    /// A missing close means the period never closes, Google encodes places open 24/7
+    /// as a single period opening on Sunday at 00:00 without a close.
+    pub fn from_minutes(periods: impl IntoIterator<Item = (u32, Option<u32>)>) -> Self {
+        let intervals = periods
+            .into_iter()
+            .map(|(open, close)| {
+                let open = open % MINUTES_PER_WEEK;
+                let end = match close.map(|close| close % MINUTES_PER_WEEK) {
+                    Some(close) if close > open => close,
+                    // Overnight periods closing after the end of the week.
+                    Some(close) if close < open => close + MINUTES_PER_WEEK,
+                    _ => open + MINUTES_PER_WEEK,
+                };
+                WeekInterval { start: open, end }
+            })
+            .collect();
+        Self { intervals }
+    }
+
+    pub fn intervals(&self) -> &[WeekInterval] {
+        &self.intervals
+    }
+
+    /// Whether the place is open at a minute of the week in its local time.
+    pub fn is_open_at_minute(&self, minute_of_week: u32) -> bool {
+        let minute_of_week = minute_of_week % MINUTES_PER_WEEK;
+        self.intervals.iter().any(|i| i.contains(minute_of_week))
+    }
+
+    /// Whether the place is open at an instant, given its offset from UTC in minutes.
+    pub fn is_open_at(&self, instant: SystemTime, utc_offset_minutes: i32) -> bool {
+        self.is_open_at_minute(local_minute_of_week(instant, utc_offset_minutes))
+    }
+
+    fn is_open_at_local(&self, local: i64) -> bool {
+        self.is_open_at_minute(minute_of_week(local))
+    }
+
+    /// The starts and ends of the intervals around `local`, in local minutes since the epoch.
+    fn boundaries(&self, local: i64) -> impl Iterator<Item = i64> + '_ {
+        let week_start = local - minute_of_week(local) as i64;
+        (-1..=2).flat_map(move |week| {
+            let offset = week_start + week * MINUTES_PER_WEEK as i64;
+            self.intervals
+                .iter()
+                .flat_map(move |i| [offset + i.start as i64, offset + i.end as i64])
+        })
+    }
+}
+
+/// The minutes since the epoch of an instant, in the local time of a UTC offset.
+fn local_minutes(instant: SystemTime, utc_offset_minutes: i32) -> i64 {
+    let seconds = match instant.duration_since(SystemTime::UNIX_EPOCH) {
+        Ok(duration) => duration.as_secs() as i64,
+        Err(err) => -(err.duration().as_secs() as i64),
+    };
+    seconds.div_euclid(60) + utc_offset_minutes as i64
+}
+
+fn instant_from_local_minutes(local: i64, utc_offset_minutes: i32) -> SystemTime {
+    let seconds = (local - utc_offset_minutes as i64) * 60;
+    if seconds >= 0 {
+        SystemTime::UNIX_EPOCH + Duration::from_secs(seconds as u64)
+    } else {
+        SystemTime::UNIX_EPOCH - Duration::from_secs(seconds.unsigned_abs())
+    }
+}
+
+fn minute_of_week(local: i64) -> u32 {
+    // 1970-01-01 was a Thursday.
+    let weekday = (local.div_euclid(MINUTES_PER_DAY as i64) + 4).rem_euclid(7);
+    (weekday * MINUTES_PER_DAY as i64 + local.rem_euclid(MINUTES_PER_DAY as i64)) as u32
+}
+
+/// The minute of the week, counted from Sunday 00:00, of an instant in a UTC offset.
+pub fn local_minute_of_week(instant: SystemTime, utc_offset_minutes: i32) -> u32 {
+    minute_of_week(local_minutes(instant, utc_offset_minutes))
+}
+
+/// Periods of specific dates, covering a window of days, in local minutes since the epoch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct DatedHours {
+    window: Range<i64>,
+    intervals: Vec<Range<i64>>,
+}
+
+impl DatedHours {
+    /// Reads the dated periods of `current_opening_hours`, which already include the
+    /// exceptional hours of its `special_days`. Returns `None` if a period has no date.
+    fn from_current(current: &PlaceOpeningHours) -> Option<Self> {
+        let mut opens = vec![];
+        let mut intervals = vec![];
+        for period in current.periods.iter().flatten() {
+            let open = period.open.as_ref()?;
+            let open_minutes = dated_minutes(open)?;
+            opens.push(open_minutes);
+
+            // Truncated times are the edges of the covered days, not an opening or closing.
+            let start = match open.truncated {
+                Some(true) => i64::MIN,
+                _ => open_minutes,
+            };
+            let end = match &period.close {
+                Some(close) if close.truncated == Some(true) => i64::MAX,
+                Some(close) => dated_minutes(close)?,
+                None => open_minutes + MINUTES_PER_WEEK as i64,
+            };
+            intervals.push(start..end);
+        }
+
+        // A special day without periods is a day the place is exceptionally closed.
+        let special_days = current
+            .special_days
+            .iter()
+            .flatten()
+            .filter_map(|day| parse_date(day.date.as_deref()?));
+        let first_day = opens
+            .iter()
+            .map(|open| open.div_euclid(MINUTES_PER_DAY as i64))
+            .chain(special_days)
+            .min()?;
+
+        let start = first_day * MINUTES_PER_DAY as i64;
+        Some(Self {
+            window: start..start + MINUTES_PER_WEEK as i64,
+            intervals,
+        })
+    }
+}
+
+/// Regular weekly hours of a place, overridden by dated hours where they are known.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpeningSchedule {
+    regular: WeeklyHours,
+    dated: Option<DatedHours>,
+    utc_offset_minutes: i32,
+}
+
+impl OpeningSchedule {
+    pub fn new(regular: WeeklyHours, utc_offset_minutes: i32) -> Self {
+        Self {
+            regular,
+            dated: None,
+            utc_offset_minutes,
+        }
+    }
+
+    /// Overrides the regular hours with the dated periods of `current_opening_hours`,
+    /// including exceptional hours and closures, for the 7 days they cover.
+    pub fn with_current_hours(mut self, current: &PlaceOpeningHours) -> Self {
+        self.dated = DatedHours::from_current(current);
+        self
+    }
+
+    fn is_open_at_local(&self, local: i64) -> bool {
+        match &self.dated {
+            Some(dated) if dated.window.contains(&local) => {
+                dated.intervals.iter().any(|i| i.contains(&local))
+            }
+            _ => self.regular.is_open_at_local(local),
+        }
+    }
+
+    /// The first minute after `local` at which the place becomes open, or closed.
+    fn next_transition(&self, local: i64, open: bool) -> Option<i64> {
+        let mut candidates = self.regular.boundaries(local).collect::<Vec<_>>();
+        if let Some(dated) = &self.dated {
+            candidates.extend([dated.window.start, dated.window.end]);
+            candidates.extend(dated.intervals.iter().flat_map(|i| [i.start, i.end]));
+        }
+        candidates.retain(|c| *c > local && *c <= local + SEARCH_HORIZON);
+        candidates.sort_unstable();
+        candidates.dedup();
+
+        candidates
+            .into_iter()
+            .find(|c| self.is_open_at_local(*c) == open && self.is_open_at_local(c - 1) != open)
+    }
+
+    pub fn is_open_at(&self, instant: SystemTime) -> bool {
+        self.is_open_at_local(local_minutes(instant, self.utc_offset_minutes))
+    }
+
+    /// The next time the place opens after `instant`, `None` if it is always open or
+    /// never opens within the next 8 days.
+    pub fn next_open_after(&self, instant: SystemTime) -> Option<SystemTime> {
+        let local = local_minutes(instant, self.utc_offset_minutes);
+        self.next_transition(local, true)
+            .map(|open| instant_from_local_minutes(open, self.utc_offset_minutes))
+    }
+
+    /// The next time the place closes after `instant`, `None` if it is always open or
+    /// does not close within the next 8 days.
+    pub fn next_close_after(&self, instant: SystemTime) -> Option<SystemTime> {
+        let local = local_minutes(instant, self.utc_offset_minutes);
+        self.next_transition(local, false)
+            .map(|close| instant_from_local_minutes(close, self.utc_offset_minutes))
+    }
+
+    /// The minutes until the place closes, `None` if it is closed at `instant` or never closes.
+    pub fn closes_in_minutes(&self, instant: SystemTime) -> Option<u32> {
+        let local = local_minutes(instant, self.utc_offset_minutes);
+        if !self.is_open_at_local(local) {
+            return None;
+        }
+        self.next_transition(local, false)
+            .map(|close| (close - local) as u32)
+    }
+
+    /// The minutes until the place opens, `None` if it is open at `instant` or does not
+    /// open within the next 8 days.
+    pub fn opens_in_minutes(&self, instant: SystemTime) -> Option<u32> {
+        let local = local_minutes(instant, self.utc_offset_minutes);
+        if self.is_open_at_local(local) {
+            return None;
+        }
+        self.next_transition(local, true)
+            .map(|open| (open - local) as u32)
+    }
+}
+
+/// Parses a `YYYY-MM-DD` date into days since the epoch.
+fn parse_date(date: &str) -> Option<i64> {
+    let mut parts = date.splitn(3, '-');
+    let year: i64 = parts.next()?.parse().ok()?;
+    let month: u32 = parts.next()?.parse().ok()?;
+    let day: u32 = parts.next()?.parse().ok()?;
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+
+    // Days from civil, counting years from March so that leap days come last.
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) as i64 + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    Some(era * 146_097 + day_of_era - 719_468)
+}
+
+fn dated_minutes(time: &OpeningHoursTime) -> Option<i64> {
+    let day = parse_date(time.date.as_deref()?)?;
+    Some(day * MINUTES_PER_DAY as i64 + time.time_of_day()?.minutes_since_midnight() as i64)
+}
+
+/// The minute of the week of a day and a time of day.
+pub(crate) fn minute_of_week_of(day: Option<Weekday>, time: Option<TimeOfDay>) -> Option<u32> {
+    Some(day?.index() as u32 * MINUTES_PER_DAY + time?.minutes_since_midnight())
+}
+
+/// The `(open, close)` minutes of the week of a legacy period, skipping invalid periods.
+fn period_minutes(
+    open: Option<(Option<Weekday>, Option<TimeOfDay>)>,
+    close: Option<(Option<Weekday>, Option<TimeOfDay>)>,
+) -> Option<(u32, Option<u32>)> {
+    let (day, time) = open?;
+    let open = minute_of_week_of(day, time)?;
+    let close = match close {
+        Some((day, time)) => Some(minute_of_week_of(day, time)?),
+        None => None,
+    };
+    Some((open, close))
+}
+
+impl OpeningHours {
+    pub fn weekly_hours(&self) -> WeeklyHours {
+        let day_time = |t: &DayTime| (t.weekday(), t.time_of_day());
+        WeeklyHours::from_minutes(self.periods.iter().flatten().filter_map(|period| {
+            period_minutes(
+                period.open.as_ref().map(day_time),
+                period.close.as_ref().map(day_time),
+            )
+        }))
+    }
+
+    /// Whether the place is open at an instant, given its `utc_offset` in minutes.
+    pub fn is_open_at(&self, instant: SystemTime, utc_offset_minutes: i32) -> bool {
+        self.weekly_hours().is_open_at(instant, utc_offset_minutes)
+    }
+}
+
+impl PlaceOpeningHours {
+    pub fn weekly_hours(&self) -> WeeklyHours {
+        let day_time = |t: &OpeningHoursTime| (t.weekday(), t.time_of_day());
+        WeeklyHours::from_minutes(self.periods.iter().flatten().filter_map(|period| {
+            period_minutes(
+                period.open.as_ref().map(day_time),
+                period.close.as_ref().map(day_time),
+            )
+        }))
+    }
+
+    /// Whether the place is open at an instant, given its `utc_offset` in minutes.
+    pub fn is_open_at(&self, instant: SystemTime, utc_offset_minutes: i32) -> bool {
+        self.weekly_hours().is_open_at(instant, utc_offset_minutes)
+    }
+}
+
+impl PlaceDetailsPlace {
+    /// The opening schedule of the place: its regular `opening_hours`, overridden by the
+    /// exceptional hours of `current_opening_hours` when they were fetched.
+    ///
+    /// Returns `None` when no opening hours or no `utc_offset` were fetched.
+    pub fn opening_schedule(&self) -> Option<OpeningSchedule> {
+        let utc_offset = self.utc_offset?;
+        let regular = match (&self.opening_hours, &self.current_opening_hours) {
+            (Some(regular), _) => regular.weekly_hours(),
+            (None, Some(current)) => current.weekly_hours(),
+            (None, None) => return None,
+        };
+
+        let schedule = OpeningSchedule::new(regular, utc_offset);
+        Some(match &self.current_opening_hours {
+            Some(current) => schedule.with_current_hours(current),
+            None => schedule,
+        })
+    }
+
+    /// Whether the place is open at an instant, see `opening_schedule`.
+    pub fn is_open_at(&self, instant: SystemTime) -> Option<bool> {
+        Some(self.opening_schedule()?.is_open_at(instant))
+    }
+
+    /// The next time the place opens after an instant, see `opening_schedule`.
+    pub fn next_open_after(&self, instant: SystemTime) -> Option<SystemTime> {
+        self.opening_schedule()?.next_open_after(instant)
+    }
+
+    /// The next time the place closes after an instant, see `opening_schedule`.
+    pub fn next_close_after(&self, instant: SystemTime) -> Option<SystemTime> {
+        self.opening_schedule()?.next_close_after(instant)
+    }
+
+    /// The minutes until the place closes, `None` if it is closed, see `opening_schedule`.
+    pub fn closes_in_minutes(&self, instant: SystemTime) -> Option<u32> {
+        self.opening_schedule()?.closes_in_minutes(instant)
+    }
+
+    /// The minutes until the place opens, `None` if it is open, see `opening_schedule`.
+    pub fn opens_in_minutes(&self, instant: SystemTime) -> Option<u32> {
+        self.opening_schedule()?.opens_in_minutes(instant)
+    }
+
+    /// The types of the `secondary_opening_hours` of the place, e.g. its kitchen or delivery.
+    pub fn secondary_hours_types(&self) -> Vec<OpeningHoursType> {
+        self.secondary_opening_hours
+            .iter()
+            .flatten()
+            .filter_map(|hours| hours.place_opening_hours_type)
+            .collect()
+    }
+
+    /// The secondary opening hours of a type, if the place has them.
+    pub fn secondary_hours(&self, hours_type: &OpeningHoursType) -> Option<&PlaceOpeningHours> {
+        self.secondary_opening_hours
+            .iter()
+            .flatten()
+            .find(|hours| hours.place_opening_hours_type.as_ref() == Some(hours_type))
+    }
+
+    /// The schedule of the secondary opening hours of a type, including their dated
+    /// exceptions. Returns `None` when the place has no such hours or no `utc_offset`.
+    pub fn secondary_schedule(&self, hours_type: &OpeningHoursType) -> Option<OpeningSchedule> {
+        let hours = self.secondary_hours(hours_type)?;
+        Some(OpeningSchedule::new(hours.weekly_hours(), self.utc_offset?).with_current_hours(hours))
+    }
+
+    /// Whether the service of a type, e.g. the kitchen, is open at an instant.
+    pub fn is_secondary_open_at(
+        &self,
+        hours_type: &OpeningHoursType,
+        instant: SystemTime,
+    ) -> Option<bool> {
+        Some(self.secondary_schedule(hours_type)?.is_open_at(instant))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        local_minute_of_week, minute_of_week_of, parse_date, OpeningSchedule, WeeklyHours,
+        MINUTES_PER_DAY,
+    };
+    use crate::types::constants::place::OpeningHours;
+    use crate::types::constants::{OpeningHoursType, PlaceDetailsPlace, TimeOfDay, Weekday};
+    use std::time::{Duration, SystemTime};
+
+    fn minute(day: u32, hour: u32, minute: u32) -> u32 {
+        day * MINUTES_PER_DAY + hour * 60 + minute
+    }
+
+    fn hours(json: serde_json::Value) -> OpeningHours {
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn test_minute_of_week_of() {
+        let time = |time: &str| time.parse::<TimeOfDay>().ok();
+        assert_eq!(
+            minute_of_week_of(Some(Weekday::Monday), time("0930")),
+            Some(minute(1, 9, 30))
+        );
+        assert_eq!(
+            minute_of_week_of(Some(Weekday::Saturday), time("2400")),
+            Some(minute(7, 0, 0))
+        );
+        assert_eq!(minute_of_week_of(None, time("0930")), None);
+        assert_eq!(minute_of_week_of(Some(Weekday::Monday), None), None);
+    }
+
+    #[test]
+    fn test_invalid_times_are_skipped() {
+        let period = |day: u8, time: &str| serde_json::json!({ "periods": [{ "open": { "day": day, "time": time } }] });
+        assert_eq!(
+            hours(period(7, "0930")).weekly_hours(),
+            WeeklyHours::default()
+        );
+        assert_eq!(
+            hours(period(1, "9:30")).weekly_hours(),
+            WeeklyHours::default()
+        );
+        assert_eq!(
+            hours(period(1, "0930")).weekly_hours(),
+            WeeklyHours::from_minutes([(minute(1, 9, 30), None)])
+        );
+    }
+
+    #[test]
+    fn test_local_minute_of_week() {
+        // 2024-01-01 00:00 UTC was a Monday.
+        let instant = SystemTime::UNIX_EPOCH + Duration::from_secs(1_704_067_200);
+        assert_eq!(local_minute_of_week(instant, 0), minute(1, 0, 0));
+        assert_eq!(local_minute_of_week(instant, 90), minute(1, 1, 30));
+        assert_eq!(local_minute_of_week(instant, -60), minute(0, 23, 0));
+        assert_eq!(
+            local_minute_of_week(SystemTime::UNIX_EPOCH, 0),
+            minute(4, 0, 0)
+        );
+    }
+
+    #[test]
+    fn test_overnight_and_week_wrap_around() {
+        let hours = hours(serde_json::json!({
+            "periods": [
+                { "open": { "day": 5, "time": "1800" }, "close": { "day": 6, "time": "0200" } },
+                { "open": { "day": 6, "time": "2200" }, "close": { "day": 0, "time": "0300" } }
+            ]
+        }))
+        .weekly_hours();
+
+        assert!(hours.is_open_at_minute(minute(5, 23, 59)));
+        assert!(hours.is_open_at_minute(minute(6, 1, 0)));
+        assert!(!hours.is_open_at_minute(minute(6, 2, 0)));
+        assert!(hours.is_open_at_minute(minute(6, 23, 0)));
+        assert!(hours.is_open_at_minute(minute(0, 2, 59)));
+        assert!(!hours.is_open_at_minute(minute(0, 3, 0)));
+        assert!(!hours.is_open_at_minute(minute(3, 12, 0)));
+    }
+
+    #[test]
+    fn test_always_open() {
+        let hours = hours(serde_json::json!({
+            "periods": [{ "open": { "day": 0, "time": "0000" } }]
+        }))
+        .weekly_hours();
+
+        assert_eq!(hours, WeeklyHours::from_minutes([(0, None)]));
+        assert!(hours.is_open_at_minute(minute(0, 0, 0)));
+        assert!(hours.is_open_at_minute(minute(3, 12, 0)));
+        assert!(hours.is_open_at_minute(minute(6, 23, 59)));
+    }
+
+    #[test]
+    fn test_place_is_open_at() {
+        let place: PlaceDetailsPlace = serde_json::from_value(serde_json::json!({
+            "place_id": "id",
+            "utc_offset": 60,
+            "opening_hours": {
+                "periods": [{ "open": { "day": 1, "time": "0900" }, "close": { "day": 1, "time": "1700" } }]
+            }
+        }))
+        .unwrap();
+
+        // Monday 2024-01-01 07:30 UTC is 08:30 local time, 08:30 UTC is 09:30.
+        let monday = SystemTime::UNIX_EPOCH + Duration::from_secs(1_704_067_200);
+        assert_eq!(
+            place.is_open_at(monday + Duration::from_secs(450 * 60)),
+            Some(false)
+        );
+        assert_eq!(
+            place.is_open_at(monday + Duration::from_secs(510 * 60)),
+            Some(true)
+        );
+        assert_eq!(PlaceDetailsPlace::default().is_open_at(monday), None);
+    }
+
+    #[test]
+    fn test_parse_date() {
+        assert_eq!(parse_date("1970-01-01"), Some(0));
+        assert_eq!(parse_date("2024-01-01"), Some(19_723));
+        assert_eq!(parse_date("2024-03-01"), Some(19_783));
+        assert_eq!(parse_date("2024-13-01"), None);
+    }
+
+    #[test]
+    fn test_next_open_and_close() {
+        let hours = hours(serde_json::json!({
+            "periods": [
+                { "open": { "day": 1, "time": "0900" }, "close": { "day": 1, "time": "1700" } },
+                { "open": { "day": 5, "time": "2200" }, "close": { "day": 6, "time": "0200" } }
+            ]
+        }));
+        let schedule = OpeningSchedule::new(hours.weekly_hours(), 0);
+        // Monday 2024-01-01 00:00 UTC.
+        let monday = SystemTime::UNIX_EPOCH + Duration::from_secs(1_704_067_200);
+        let at = |minutes: u64| monday + Duration::from_secs(minutes * 60);
+
+        assert_eq!(schedule.next_open_after(monday), Some(at(9 * 60)));
+        assert_eq!(schedule.opens_in_minutes(monday), Some(9 * 60));
+        assert_eq!(schedule.closes_in_minutes(at(16 * 60 + 30)), Some(30));
+        assert_eq!(schedule.closes_in_minutes(at(18 * 60)), None);
+        assert_eq!(
+            schedule.next_close_after(at(18 * 60)),
+            Some(at(5 * 24 * 60 + 2 * 60))
+        );
+        assert_eq!(
+            schedule.next_open_after(at(10 * 60)),
+            Some(at(4 * 24 * 60 + 22 * 60))
+        );
+
+        let always_open = OpeningSchedule::new(WeeklyHours::from_minutes([(0, None)]), 0);
+        assert_eq!(always_open.next_close_after(monday), None);
+        assert_eq!(always_open.next_open_after(monday), None);
+    }
+
+    #[test]
+    fn test_exceptional_hours() {
+        let place: PlaceDetailsPlace = serde_json::from_value(serde_json::json!({
+            "place_id": "id",
+            "utc_offset": 60,
+            "opening_hours": {
+                "periods": (0..7).map(|day| serde_json::json!({
+                    "open": { "day": day, "time": "0900" }, "close": { "day": day, "time": "1700" }
+                })).collect::<Vec<_>>()
+            },
+            "current_opening_hours": {
+                "periods": (2..=7).map(|date| serde_json::json!({
+                    "open": { "day": date % 7, "time": "0900", "date": format!("2024-01-0{}", date) },
+                    "close": { "day": date % 7, "time": if date == 2 { "1200" } else { "1700" }, "date": format!("2024-01-0{}", date) }
+                })).collect::<Vec<_>>(),
+                "special_days": [
+                    { "date": "2024-01-01", "exceptional_hours": true },
+                    { "date": "2024-01-02", "exceptional_hours": true }
+                ]
+            }
+        }))
+        .unwrap();
+
+        // Monday 2024-01-01 is a holiday, 10:00 local time is 09:00 UTC.
+        let monday = SystemTime::UNIX_EPOCH + Duration::from_secs(1_704_067_200);
+        let local = |minutes: u64| monday + Duration::from_secs((minutes - 60) * 60);
+
+        assert_eq!(place.is_open_at(local(10 * 60)), Some(false));
+        assert_eq!(place.next_open_after(local(10 * 60)), Some(local(33 * 60)));
+        assert_eq!(place.opens_in_minutes(local(10 * 60)), Some(23 * 60));
+        // Tuesday closes early at 12:00.
+        assert_eq!(place.closes_in_minutes(local(35 * 60)), Some(60));
+        // The following Monday is outside the current hours and uses the regular hours.
+        assert_eq!(place.is_open_at(local(7 * 24 * 60 + 10 * 60)), Some(true));
+    }
+
+    #[test]
+    fn test_truncated_current_hours() {
+        let place: PlaceDetailsPlace = serde_json::from_value(serde_json::json!({
+            "place_id": "id",
+            "utc_offset": 0,
+            "opening_hours": { "periods": [{ "open": { "day": 0, "time": "0000" } }] },
+            "current_opening_hours": {
+                "periods": [{
+                    "open": { "day": 1, "time": "0000", "date": "2024-01-01", "truncated": true },
+                    "close": { "day": 0, "time": "2359", "date": "2024-01-07", "truncated": true }
+                }]
+            }
+        }))
+        .unwrap();
+
+        // The truncated close at the end of the covered days is not a closing time.
+        let monday = SystemTime::UNIX_EPOCH + Duration::from_secs(1_704_067_200);
+        let sunday = monday + Duration::from_secs((6 * 24 * 60 + 23 * 60 + 59) * 60);
+        assert_eq!(place.is_open_at(sunday), Some(true));
+        assert_eq!(place.next_close_after(monday), None);
+    }
+
+    #[test]
+    fn test_secondary_hours() {
+        let place: PlaceDetailsPlace = serde_json::from_value(serde_json::json!({
+            "place_id": "id",
+            "utc_offset": 0,
+            "secondary_opening_hours": [
+                {
+                    "type": "KITCHEN",
+                    "periods": [
+                        { "open": { "day": 1, "time": "1200" }, "close": { "day": 1, "time": "1400" } }
+                    ]
+                },
+                {
+                    "type": "DELIVERY",
+                    "periods": [{ "open": { "day": 0, "time": "0000" } }]
+                }
+            ]
+        }))
+        .unwrap();
+        // Monday 2024-01-01 00:00 UTC.
+        let monday = SystemTime::UNIX_EPOCH + Duration::from_secs(1_704_067_200);
+        let at = |minutes: u64| monday + Duration::from_secs(minutes * 60);
+
+        assert_eq!(
+            place.secondary_hours_types(),
+            vec![OpeningHoursType::Kitchen, OpeningHoursType::Delivery]
+        );
+        let kitchen = OpeningHoursType::Kitchen;
+        assert_eq!(
+            place.is_secondary_open_at(&kitchen, at(13 * 60)),
+            Some(true)
+        );
+        assert_eq!(
+            place.is_secondary_open_at(&kitchen, at(15 * 60)),
+            Some(false)
+        );
+        assert_eq!(
+            place.is_secondary_open_at(&OpeningHoursType::Delivery, at(15 * 60)),
+            Some(true)
+        );
+        assert_eq!(
+            place.is_secondary_open_at(&OpeningHoursType::DriveThrough, at(15 * 60)),
+            None
+        );
+    }
+}
@@ -0,0 +1,282 @@
+use std::fmt;
+
+/// The format of an image, sniffed from its magic bytes.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ImageFormat {
+    Jpeg,
+    Png,
+    Gif,
+    WebP,
+    Bmp,
+}
+
+impl ImageFormat {
+    /// Detects the format from the first bytes of an image.
+    pub fn sniff(bytes: &[u8]) -> Option<Self> {
+        match bytes {
+            [0xFF, 0xD8, 0xFF, ..] => Some(ImageFormat::Jpeg),
+            [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, ..] => Some(ImageFormat::Png),
+            [b'G', b'I', b'F', b'8', b'7' | b'9', b'a', ..] => Some(ImageFormat::Gif),
+            [b'R', b'I', b'F', b'F', _, _, _, _, b'W', b'E', b'B', b'P', ..] => {
+                Some(ImageFormat::WebP)
+            }
+            // "BM" alone is too common, the DIB header size must be one of the known versions.
+            [b'B', b'M', ..]
+                if matches!(le_u32(bytes, 14), Some(12 | 40 | 52 | 56 | 64 | 108 | 124)) =>
+            {
+                Some(ImageFormat::Bmp)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            ImageFormat::Jpeg => "image/jpeg",
+            ImageFormat::Png => "image/png",
+            ImageFormat::Gif => "image/gif",
+            ImageFormat::WebP => "image/webp",
+            ImageFormat::Bmp => "image/bmp",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ImageFormat::Jpeg => "jpg",
+            ImageFormat::Png => "png",
+            ImageFormat::Gif => "gif",
+            ImageFormat::WebP => "webp",
+            ImageFormat::Bmp => "bmp",
+        }
+    }
+
+    /// Decodes the pixel dimensions from the header of an image of this format.
+    pub fn dimensions(&self, bytes: &[u8]) -> Option<(u32, u32)> {
+        match self {
+            ImageFormat::Jpeg => jpeg_dimensions(bytes),
+            ImageFormat::Png => Some((be_u32(bytes, 16)?, be_u32(bytes, 20)?)),
+            ImageFormat::Gif => Some((le_u16(bytes, 6)? as u32, le_u16(bytes, 8)? as u32)),
+            ImageFormat::WebP => webp_dimensions(bytes),
+            // The height is negative for top-down bitmaps.
+            ImageFormat::Bmp => Some((
+                le_u32(bytes, 18)?,
+                (le_u32(bytes, 22)? as i32).unsigned_abs(),
+            )),
+        }
+    }
+}
+
+/// A fetched photo together with what is known about its content.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PhotoData {
+    pub bytes: Vec<u8>,
+    /// The `Content-Type` announced by the server.
+    pub content_type: Option<String>,
+    /// The format sniffed from the bytes, which takes precedence over the content type.
+    pub format: ImageFormat,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+}
+
+impl PhotoData {
+    /// Inspects the bytes of a photo.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NotAnImage` when the bytes are not a known image format, e.g. an html
+    /// error page returned when the quota is exceeded.
+    pub fn new(bytes: Vec<u8>, content_type: Option<String>) -> Result<Self, NotAnImage> {
+        let format = match ImageFormat::sniff(&bytes) {
+            Some(format) => format,
+            None => return Err(NotAnImage::new(&bytes, content_type)),
+        };
+        let dimensions = format.dimensions(&bytes);
+
+        Ok(Self {
+            bytes,
+            content_type,
+            format,
+            width: dimensions.map(|(width, _)| width),
+            height: dimensions.map(|(_, height)| height),
+        })
+    }
+
+    /// The MIME type of the photo, from the sniffed format.
+    pub fn mime_type(&self) -> &'static str {
+        self.format.mime_type()
+    }
+}
+
+/// The body of a photo response was not an image.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotAnImage {
+    pub content_type: Option<String>,
+    /// The start of the body, lossily decoded as text.
+    pub preview: String,
+}
+
+impl NotAnImage {
+    pub(crate) fn new(bytes: &[u8], content_type: Option<String>) -> Self {
+        let preview = String::from_utf8_lossy(&bytes[..bytes.len().min(200)]);
+        Self {
+            content_type,
+            preview: preview.trim().to_string(),
+        }
+    }
+}
+
+impl fmt::Display for NotAnImage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "body is not an image (content type: {}): {}",
+            self.content_type.as_deref().unwrap_or("unknown"),
+            self.preview
+        )
+    }
+}
+
+impl std::error::Error for NotAnImage {}
+
+fn be_u16(bytes: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_be_bytes(
+        bytes.get(offset..offset + 2)?.try_into().ok()?,
+    ))
+}
+
+fn be_u32(bytes: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_be_bytes(
+        bytes.get(offset..offset + 4)?.try_into().ok()?,
+    ))
+}
+
+fn le_u16(bytes: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_le_bytes(
+        bytes.get(offset..offset + 2)?.try_into().ok()?,
+    ))
+}
+
+fn le_u24(bytes: &[u8], offset: usize) -> Option<u32> {
+    let b = bytes.get(offset..offset + 3)?;
+    Some(u32::from(b[0]) | u32::from(b[1]) << 8 | u32::from(b[2]) << 16)
+}
+
+fn le_u32(bytes: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_le_bytes(
+        bytes.get(offset..offset + 4)?.try_into().ok()?,
+    ))
+}
+
+fn jpeg_dimensions(bytes: &[u8]) -> Option<(u32, u32)> {
+    let mut i = 2;
+    loop {
+        if *bytes.get(i)? != 0xFF {
+            return None;
+        }
+        let marker = *bytes.get(i + 1)?;
+        match marker {
+            // Fill bytes before a marker.
+            0xFF => i += 1,
+            // Markers without a length.
+            0x01 | 0xD0..=0xD9 => i += 2,
+            // Start of frame, except DHT, JPG and DAC which share the range.
+            0xC0..=0xCF if !matches!(marker, 0xC4 | 0xC8 | 0xCC) => {
+                let height = be_u16(bytes, i + 5)? as u32;
+                let width = be_u16(bytes, i + 7)? as u32;
+                return Some((width, height));
+            }
+            _ => i += 2 + be_u16(bytes, i + 2)? as usize,
+        }
+    }
+}
+
+fn webp_dimensions(bytes: &[u8]) -> Option<(u32, u32)> {
+    match bytes.get(12..16)? {
+        b"VP8 " => Some((
+            (le_u16(bytes, 26)? & 0x3FFF) as u32,
+            (le_u16(bytes, 28)? & 0x3FFF) as u32,
+        )),
+        b"VP8L" => {
+            let bits = le_u32(bytes, 21)?;
+            Some(((bits & 0x3FFF) + 1, ((bits >> 14) & 0x3FFF) + 1))
+        }
+        b"VP8X" => Some((le_u24(bytes, 24)? + 1, le_u24(bytes, 27)? + 1)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ImageFormat, PhotoData};
+
+    fn png(width: u32, height: u32) -> Vec<u8> {
+        let mut bytes = vec![0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+        bytes.extend_from_slice(&13u32.to_be_bytes());
+        bytes.extend_from_slice(b"IHDR");
+        bytes.extend_from_slice(&width.to_be_bytes());
+        bytes.extend_from_slice(&height.to_be_bytes());
+        bytes
+    }
+
+    #[test]
+    fn test_png() {
+        let photo = PhotoData::new(png(400, 300), Some(String::from("image/png"))).unwrap();
+        assert_eq!(photo.format, ImageFormat::Png);
+        assert_eq!(photo.mime_type(), "image/png");
+        assert_eq!((photo.width, photo.height), (Some(400), Some(300)));
+    }
+
+    #[test]
+    fn test_jpeg() {
+        let mut bytes = vec![0xFF, 0xD8];
+        // APP0 segment of 16 bytes, then a baseline start of frame.
+        bytes.extend_from_slice(&[0xFF, 0xE0, 0x00, 0x10]);
+        bytes.extend_from_slice(&[0; 14]);
+        bytes.extend_from_slice(&[0xFF, 0xC0, 0x00, 0x11, 0x08, 0x03, 0x00, 0x04, 0x00]);
+
+        let photo = PhotoData::new(bytes, None).unwrap();
+        assert_eq!(photo.format, ImageFormat::Jpeg);
+        assert_eq!((photo.width, photo.height), (Some(1024), Some(768)));
+    }
+
+    #[test]
+    fn test_gif_and_webp() {
+        let gif = b"GIF89a\x20\x03\x58\x02".to_vec();
+        let photo = PhotoData::new(gif, None).unwrap();
+        assert_eq!((photo.width, photo.height), (Some(800), Some(600)));
+
+        let mut webp = b"RIFF\0\0\0\0WEBPVP8X\x0a\0\0\0\0\0\0\0".to_vec();
+        webp.extend_from_slice(&[0x1F, 0x03, 0x00, 0x57, 0x02, 0x00]);
+        let photo = PhotoData::new(webp, None).unwrap();
+        assert_eq!(photo.format, ImageFormat::WebP);
+        assert_eq!((photo.width, photo.height), (Some(800), Some(600)));
+    }
+
+    #[test]
+    fn test_bmp() {
+        let mut bmp = b"BM".to_vec();
+        bmp.extend_from_slice(&[0; 12]);
+        bmp.extend_from_slice(&40u32.to_le_bytes());
+        bmp.extend_from_slice(&640u32.to_le_bytes());
+        bmp.extend_from_slice(&(-480i32).to_le_bytes());
+        let photo = PhotoData::new(bmp, None).unwrap();
+        assert_eq!(photo.format, ImageFormat::Bmp);
+        assert_eq!((photo.width, photo.height), (Some(640), Some(480)));
+
+        assert_eq!(ImageFormat::sniff(b"BM, not a bitmap"), None);
+    }
+
+    #[test]
+    fn test_not_an_image() {
+        let err = PhotoData::new(
+            b"<html><body>You have exceeded your daily request quota.</body></html>".to_vec(),
+            Some(String::from("text/html; charset=UTF-8")),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.content_type.as_deref(),
+            Some("text/html; charset=UTF-8")
+        );
+        assert!(err.preview.contains("exceeded your daily request quota"));
+    }
+}
@@ -0,0 +1,194 @@
+use crate::types::photo_data::{ImageFormat, PhotoData};
+use image::codecs::jpeg::JpegEncoder;
+use image::codecs::png::PngEncoder;
+use image::imageops::FilterType;
+use image::{DynamicImage, ImageEncoder, ImageError};
+
+/// How a variant is resized from the original photo.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Resize {
+    /// Keep the original size.
+    Original,
+    /// Scale down to fit within the bounds, keeping the aspect ratio. Never upscales.
+    Fit { max_width: u32, max_height: u32 },
+    /// Scale and crop around the center to exactly the given size.
+    Fill { width: u32, height: u32 },
+}
+
+/// How a variant is encoded.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Encoding {
+    /// JPEG with a quality between 1 and 100.
+    Jpeg {
+        quality: u8,
+    },
+    /// Lossy WebP with a quality between 0 and 100.
+    WebP {
+        quality: f32,
+    },
+    Png,
+}
+
+/// A resized and re-encoded variant of a photo, e.g. a square thumbnail.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PhotoVariant {
+    pub resize: Resize,
+    pub encoding: Encoding,
+}
+
+impl PhotoVariant {
+    pub fn new(resize: Resize, encoding: Encoding) -> Self {
+        Self { resize, encoding }
+    }
+
+    /// A square thumbnail cropped around the center, encoded as JPEG.
+    pub fn square(size: u32, quality: u8) -> Self {
+        Self::new(
+            Resize::Fill {
+                width: size,
+                height: size,
+            },
+            Encoding::Jpeg { quality },
+        )
+    }
+
+    fn apply(&self, original: &DynamicImage) -> Result<PhotoData, ImageError> {
+        let image = match self.resize {
+            Resize::Original => original.clone(),
+            Resize::Fit {
+                max_width,
+                max_height,
+            } if original.width() > max_width || original.height() > max_height => {
+                original.resize(max_width, max_height, FilterType::Lanczos3)
+            }
+            Resize::Fit { .. } => original.clone(),
+            Resize::Fill { width, height } => {
+                original.resize_to_fill(width, height, FilterType::Lanczos3)
+            }
+        };
+
+        let (width, height) = (image.width(), image.height());
+        let (bytes, format) = match self.encoding {
+            Encoding::Jpeg { quality } => {
+                let mut bytes = Vec::new();
+                JpegEncoder::new_with_quality(&mut bytes, quality.clamp(1, 100))
+                    .encode_image(&image.to_rgb8())?;
+                (bytes, ImageFormat::Jpeg)
+            }
+            Encoding::WebP { quality } => {
+                let rgba = image.to_rgba8();
+                let bytes = webp::Encoder::from_rgba(&rgba, width, height)
+                    .encode(quality.clamp(0.0, 100.0))
+                    .to_vec();
+                (bytes, ImageFormat::WebP)
+            }
+            Encoding::Png => {
+                let rgba = image.to_rgba8();
+                let mut bytes = Vec::new();
+                PngEncoder::new(&mut bytes).write_image(
+                    &rgba,
+                    width,
+                    height,
+                    image::ExtendedColorType::Rgba8,
+                )?;
+                (bytes, ImageFormat::Png)
+            }
+        };
+
+        Ok(PhotoData {
+            bytes,
+            content_type: Some(String::from(format.mime_type())),
+            format,
+            width: Some(width),
+            height: Some(height),
+        })
+    }
+}
+
+impl PhotoData {
+    /// Produces a resized and re-encoded variant of the photo.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `ImageError` if the photo cannot be decoded or the variant cannot be encoded.
+    pub fn to_variant(&self, variant: &PhotoVariant) -> Result<PhotoData, ImageError> {
+        variant.apply(&image::load_from_memory(&self.bytes)?)
+    }
+
+    /// Produces several variants of the photo, decoding the original only once.
+    ///
+    /// # Errors
+    ///
+    /// See `to_variant`.
+    pub fn to_variants(&self, variants: &[PhotoVariant]) -> Result<Vec<PhotoData>, ImageError> {
+        let original = image::load_from_memory(&self.bytes)?;
+        variants
+            .iter()
+            .map(|variant| variant.apply(&original))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Encoding, PhotoVariant, Resize};
+    use crate::types::photo_data::{ImageFormat, PhotoData};
+    use image::{DynamicImage, ImageFormat as Format, RgbImage};
+    use std::io::Cursor;
+
+    fn original(width: u32, height: u32) -> PhotoData {
+        let image = DynamicImage::ImageRgb8(RgbImage::from_fn(width, height, |x, y| {
+            image::Rgb([(x % 256) as u8, (y % 256) as u8, 128])
+        }));
+        let mut bytes = Cursor::new(Vec::new());
+        image.write_to(&mut bytes, Format::Png).unwrap();
+        PhotoData::new(bytes.into_inner(), Some(String::from("image/png"))).unwrap()
+    }
+
+    #[test]
+    fn test_variants() {
+        let photo = original(400, 200);
+        let variants = photo
+            .to_variants(&[
+                PhotoVariant::square(64, 80),
+                PhotoVariant::new(
+                    Resize::Fit {
+                        max_width: 100,
+                        max_height: 100,
+                    },
+                    Encoding::WebP { quality: 75.0 },
+                ),
+                PhotoVariant::new(
+                    Resize::Fit {
+                        max_width: 800,
+                        max_height: 800,
+                    },
+                    Encoding::Png,
+                ),
+            ])
+            .unwrap();
+
+        let sizes = variants
+            .iter()
+            .map(|v| (v.format, v.width, v.height))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            sizes,
+            vec![
+                (ImageFormat::Jpeg, Some(64), Some(64)),
+                (ImageFormat::WebP, Some(100), Some(50)),
+                (ImageFormat::Png, Some(400), Some(200)),
+            ]
+        );
+
+        // The encoded bytes are inspected again to make sure they match the metadata.
+        for variant in variants {
+            let reparsed = PhotoData::new(variant.bytes.clone(), None).unwrap();
+            assert_eq!(reparsed.format, variant.format);
+            assert_eq!(
+                (reparsed.width, reparsed.height),
+                (variant.width, variant.height)
+            );
+        }
+    }
+}
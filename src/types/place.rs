@@ -0,0 +1,438 @@
+use crate::types::constants::place::{
+    DayTime, Geometry, Location, OpeningHours as SearchOpeningHours, OpeningHoursTime,
+    PlaceOpeningHours,
+};
+use crate::types::constants::{
+    PlaceDetailsPlace, PlaceSearchPlace, PlaceTypes, TimeOfDay, Weekday,
+};
+use crate::types::v1;
+use crate::types::v1::{BusinessStatus, LatLng, PriceLevel, Viewport};
+use serde::{Deserialize, Serialize};
+
+/// A place independent of the endpoint or API generation that produced it.
+///
+/// Built with `From` from a legacy [`PlaceSearchPlace`] or [`PlaceDetailsPlace`],
+/// or from a Places API (New) [`v1::Place`]. Fields the source does not carry are `None`.
+#[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq)]
+pub struct Place {
+    // Basic
+    pub id: String,
+    pub name: Option<String>,
+    pub formatted_address: Option<String>,
+    pub short_address: Option<String>,
+    pub location: Option<LatLng>,
+    pub viewport: Option<Viewport>,
+    pub types: Vec<PlaceTypes>,
+    pub business_status: Option<BusinessStatus>,
+    pub google_maps_url: Option<String>,
+    pub utc_offset_minutes: Option<i32>,
+    pub wheelchair_accessible_entrance: Option<bool>,
+
+    // Contact
+    pub national_phone_number: Option<String>,
+    pub international_phone_number: Option<String>,
+    pub website: Option<String>,
+    pub opening_hours: Option<OpeningHours>,
+    pub current_opening_hours: Option<OpeningHours>,
+
+    // Atmosphere
+    pub price_level: Option<PriceLevel>,
+    pub rating: Option<f32>,
+    pub user_ratings_total: Option<u32>,
+    pub editorial_summary: Option<String>,
+    pub curbside_pickup: Option<bool>,
+    pub delivery: Option<bool>,
+    pub dine_in: Option<bool>,
+    pub reservable: Option<bool>,
+    pub takeout: Option<bool>,
+}
+
+/// Opening hours independent of the wire format.
+#[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq)]
+pub struct OpeningHours {
+    pub open_now: Option<bool>,
+    pub periods: Vec<OpeningPeriod>,
+    pub weekday_text: Vec<String>,
+}
+
+/// An opening period. A period without `close` means the place is always open.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub struct OpeningPeriod {
+    pub open: WeekTime,
+    pub close: Option<WeekTime>,
+}
+
+/// A point in the week.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub struct WeekTime {
+    pub day: Weekday,
+    pub hour: u8,
+    pub minute: u8,
+}
+
+impl WeekTime {
+    /// Converts a legacy `day` and time pair. `2400` becomes midnight of the next day.
+    fn from_legacy(day: Option<Weekday>, time: Option<TimeOfDay>) -> Option<Self> {
+        let (day, time) = match (day?, time?) {
+            (day, TimeOfDay::END_OF_DAY) => (day.succ(), TimeOfDay::MIDNIGHT),
+            (day, time) => (day, time),
+        };
+        Some(WeekTime {
+            day,
+            hour: time.hour(),
+            minute: time.minute(),
+        })
+    }
+}
+
+fn location_from_geometry(geometry: &Option<Geometry>) -> (Option<LatLng>, Option<Viewport>) {
+    let Some(geometry) = geometry else {
+        return (None, None);
+    };
+    let to_lat_lng = |location: &Location| match (location.lat, location.lon) {
+        (Some(lat), Some(lon)) => Some(LatLng::new(lat, lon)),
+        _ => None,
+    };
+
+    let location = geometry.location.as_ref().and_then(to_lat_lng);
+    let viewport = geometry.viewport.as_ref().and_then(|v| {
+        Some(Viewport {
+            low: to_lat_lng(v.southwest.as_ref()?)?,
+            high: to_lat_lng(v.northeast.as_ref()?)?,
+        })
+    });
+    (location, viewport)
+}
+
+fn user_ratings_total(total: Option<i32>) -> Option<u32> {
+    total.and_then(|t| u32::try_from(t).ok())
+}
+
+/// Converts `(open, close)` points to periods with `time`, skipping the periods it fails on.
+fn opening_periods<'a, T: 'a>(
+    periods: impl Iterator<Item = (Option<&'a T>, Option<&'a T>)>,
+    time: impl Fn(&T) -> Option<WeekTime>,
+) -> Vec<OpeningPeriod> {
+    periods
+        .filter_map(|(open, close)| {
+            // A close that cannot be converted drops the period, `None` means always open.
+            let close = match close {
+                Some(close) => Some(time(close)?),
+                None => None,
+            };
+            Some(OpeningPeriod {
+                open: time(open?)?,
+                close,
+            })
+        })
+        .collect()
+}
+
+impl From<&SearchOpeningHours> for OpeningHours {
+    fn from(hours: &SearchOpeningHours) -> Self {
+        let time = |t: &DayTime| WeekTime::from_legacy(t.weekday(), t.time_of_day());
+        OpeningHours {
+            open_now: hours.open_now,
+            periods: opening_periods(
+                hours
+                    .periods
+                    .iter()
+                    .flatten()
+                    .map(|p| (p.open.as_ref(), p.close.as_ref())),
+                time,
+            ),
+            weekday_text: hours.weekday_text.clone().unwrap_or_default(),
+        }
+    }
+}
+
+impl From<&PlaceOpeningHours> for OpeningHours {
+    fn from(hours: &PlaceOpeningHours) -> Self {
+        let time = |t: &OpeningHoursTime| WeekTime::from_legacy(t.weekday(), t.time_of_day());
+        OpeningHours {
+            open_now: hours.open_now,
+            periods: opening_periods(
+                hours
+                    .periods
+                    .iter()
+                    .flatten()
+                    .map(|p| (p.open.as_ref(), p.close.as_ref())),
+                time,
+            ),
+            weekday_text: hours.weekday_text.clone().unwrap_or_default(),
+        }
+    }
+}
+
+impl From<&v1::OpeningHours> for OpeningHours {
+    fn from(hours: &v1::OpeningHours) -> Self {
+        let time = |p: &v1::Point| {
+            Some(WeekTime {
+                day: Weekday::from_index(p.day?.into())?,
+                hour: u8::try_from(p.hour.unwrap_or(0)).ok()?,
+                minute: u8::try_from(p.minute.unwrap_or(0)).ok()?,
+            })
+        };
+        OpeningHours {
+            open_now: hours.open_now,
+            periods: opening_periods(
+                hours
+                    .periods
+                    .iter()
+                    .flatten()
+                    .map(|p| (p.open.as_ref(), p.close.as_ref())),
+                time,
+            ),
+            weekday_text: hours.weekday_descriptions.clone().unwrap_or_default(),
+        }
+    }
+}
+
+impl From<&PlaceSearchPlace> for Place {
+    fn from(place: &PlaceSearchPlace) -> Self {
+        let (location, viewport) = location_from_geometry(&place.geometry);
+        Place {
+            id: place.id.clone(),
+            name: place.name.clone(),
+            formatted_address: place.formatted_address.clone(),
+            short_address: place.vicinity.clone(),
+            location,
+            viewport,
+            types: place.types.clone().unwrap_or_default(),
+            business_status: place.business_status.clone(),
+            opening_hours: place.opening_hours.as_ref().map(OpeningHours::from),
+            price_level: place.price_level.clone(),
+            rating: place.rating,
+            user_ratings_total: user_ratings_total(place.user_ratings_total),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&PlaceDetailsPlace> for Place {
+    fn from(place: &PlaceDetailsPlace) -> Self {
+        let (location, viewport) = location_from_geometry(&place.geometry);
+        Place {
+            id: place.id.clone(),
+            name: place.name.clone(),
+            formatted_address: place.formatted_address.clone(),
+            short_address: place.vicinity.clone(),
+            location,
+            viewport,
+            types: place.types.clone().unwrap_or_default(),
+            business_status: place.business_status.clone(),
+            google_maps_url: place.url.clone(),
+            utc_offset_minutes: place.utc_offset,
+            wheelchair_accessible_entrance: place.wheelchair_accessible_entrance,
+            national_phone_number: place.formatted_phone_number.clone(),
+            international_phone_number: place.international_phone_number.clone(),
+            website: place.website.clone(),
+            opening_hours: place.opening_hours.as_ref().map(OpeningHours::from),
+            current_opening_hours: place.current_opening_hours.as_ref().map(OpeningHours::from),
+            price_level: place.price_level.clone(),
+            rating: place.rating,
+            user_ratings_total: user_ratings_total(place.user_ratings_total),
+            editorial_summary: place
+                .editorial_summary
+                .as_ref()
+                .and_then(|s| s.overview.clone()),
+            curbside_pickup: place.curbside_pickup,
+            delivery: place.delivery,
+            dine_in: place.dine_in,
+            reservable: place.reservable,
+            takeout: place.takeout,
+        }
+    }
+}
+
+impl From<&v1::Place> for Place {
+    fn from(place: &v1::Place) -> Self {
+        let id = place.id.clone().or_else(|| {
+            place
+                .name
+                .as_deref()
+                .and_then(|n| n.strip_prefix("places/"))
+                .map(String::from)
+        });
+        Place {
+            id: id.unwrap_or_default(),
+            name: place.display_name.as_ref().and_then(|n| n.text.clone()),
+            formatted_address: place.formatted_address.clone(),
+            short_address: place.short_formatted_address.clone(),
+            location: place.location,
+            viewport: place.viewport,
+            types: place.types.clone().unwrap_or_default(),
+            business_status: place.business_status.clone(),
+            google_maps_url: place.google_maps_uri.clone(),
+            utc_offset_minutes: place.utc_offset_minutes,
+            wheelchair_accessible_entrance: place
+                .accessibility_options
+                .as_ref()
+                .and_then(|a| a.wheelchair_accessible_entrance),
+            national_phone_number: place.national_phone_number.clone(),
+            international_phone_number: place.international_phone_number.clone(),
+            website: place.website_uri.clone(),
+            opening_hours: place.regular_opening_hours.as_ref().map(OpeningHours::from),
+            current_opening_hours: place.current_opening_hours.as_ref().map(OpeningHours::from),
+            price_level: place.price_level.clone(),
+            rating: place.rating,
+            user_ratings_total: user_ratings_total(place.user_rating_count),
+            editorial_summary: place
+                .editorial_summary
+                .as_ref()
+                .and_then(|s| s.text.clone()),
+            curbside_pickup: place.curbside_pickup,
+            delivery: place.delivery,
+            dine_in: place.dine_in,
+            reservable: place.reservable,
+            takeout: place.takeout,
+        }
+    }
+}
+
+impl From<PlaceSearchPlace> for Place {
+    fn from(place: PlaceSearchPlace) -> Self {
+        Place::from(&place)
+    }
+}
+
+impl From<PlaceDetailsPlace> for Place {
+    fn from(place: PlaceDetailsPlace) -> Self {
+        Place::from(&place)
+    }
+}
+
+impl From<v1::Place> for Place {
+    fn from(place: v1::Place) -> Self {
+        Place::from(&place)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{OpeningPeriod, Place, WeekTime};
+    use crate::types::constants::{PlaceDetailsPlace, Weekday};
+    use crate::types::v1;
+    use crate::types::v1::{BusinessStatus, PriceLevel};
+
+    #[test]
+    fn test_from_legacy_details() {
+        let legacy: PlaceDetailsPlace = serde_json::from_str(
+            r#"{
+                "place_id": "ChIJN1t_tDeuEmsRUsoyG83frY4",
+                "name": "Google Workshop",
+                "business_status": "OPERATIONAL",
+                "formatted_phone_number": "(02) 9374 4000",
+                "price_level": 2,
+                "user_ratings_total": 120,
+                "opening_hours": {
+                    "open_now": true,
+                    "periods": [{ "open": { "day": 1, "time": "0930" }, "close": { "day": 1, "time": "1730" } }]
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let place = Place::from(&legacy);
+        assert_eq!(place.id, "ChIJN1t_tDeuEmsRUsoyG83frY4");
+        assert_eq!(place.business_status, Some(BusinessStatus::Operational));
+        assert_eq!(
+            place.national_phone_number.as_deref(),
+            Some("(02) 9374 4000")
+        );
+        assert_eq!(place.price_level, Some(PriceLevel::Moderate));
+        assert_eq!(place.user_ratings_total, Some(120));
+        assert_eq!(
+            place.opening_hours.unwrap().periods,
+            vec![OpeningPeriod {
+                open: WeekTime {
+                    day: Weekday::Monday,
+                    hour: 9,
+                    minute: 30
+                },
+                close: Some(WeekTime {
+                    day: Weekday::Monday,
+                    hour: 17,
+                    minute: 30
+                }),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_from_legacy_midnight_close() {
+        let legacy: PlaceDetailsPlace = serde_json::from_str(
+            r#"{
+                "place_id": "ChIJN1t_tDeuEmsRUsoyG83frY4",
+                "opening_hours": {
+                    "periods": [
+                        { "open": { "day": 6, "time": "1800" }, "close": { "day": 6, "time": "2400" } },
+                        { "open": { "day": 0, "time": "0000" } }
+                    ]
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            Place::from(&legacy).opening_hours.unwrap().periods,
+            vec![
+                OpeningPeriod {
+                    open: WeekTime {
+                        day: Weekday::Saturday,
+                        hour: 18,
+                        minute: 0
+                    },
+                    close: Some(WeekTime {
+                        day: Weekday::Sunday,
+                        hour: 0,
+                        minute: 0
+                    }),
+                },
+                OpeningPeriod {
+                    open: WeekTime {
+                        day: Weekday::Sunday,
+                        hour: 0,
+                        minute: 0
+                    },
+                    close: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_from_v1() {
+        let new: v1::Place = serde_json::from_str(
+            r#"{
+                "name": "places/ChIJN1t_tDeuEmsRUsoyG83frY4",
+                "displayName": { "text": "Google Workshop" },
+                "nationalPhoneNumber": "(02) 9374 4000",
+                "priceLevel": "PRICE_LEVEL_MODERATE",
+                "userRatingCount": 120,
+                "regularOpeningHours": {
+                    "periods": [{ "open": { "day": 1, "hour": 9, "minute": 30 }, "close": { "day": 1, "hour": 17, "minute": 30 } }]
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let place = Place::from(&new);
+        assert_eq!(place.id, "ChIJN1t_tDeuEmsRUsoyG83frY4");
+        assert_eq!(place.name.as_deref(), Some("Google Workshop"));
+        assert_eq!(
+            place.national_phone_number.as_deref(),
+            Some("(02) 9374 4000")
+        );
+        assert_eq!(place.price_level, Some(PriceLevel::Moderate));
+        assert_eq!(place.user_ratings_total, Some(120));
+        assert_eq!(
+            place.opening_hours.unwrap().periods[0].close,
+            Some(WeekTime {
+                day: Weekday::Monday,
+                hour: 17,
+                minute: 30
+            })
+        );
+    }
+}
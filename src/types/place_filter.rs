@@ -0,0 +1,462 @@
+use crate::types::constants::{PlaceDetailsPlace, PlaceSearchPlace, PlaceTypes};
+use crate::types::place_search::DistancedPlace;
+use crate::types::rating_score::{wilson_lower_bound_of, BayesianAverage};
+use crate::types::v1::{BusinessStatus, PriceLevel};
+use regex::Regex;
+use std::cmp::Ordering;
+
+/// The fields of a legacy place that results can be filtered and sorted on.
+pub trait FilterablePlace {
+    fn name(&self) -> Option<&str>;
+    fn rating(&self) -> Option<f32>;
+    fn user_ratings_total(&self) -> Option<i32>;
+    fn open_now(&self) -> Option<bool>;
+    fn business_status(&self) -> Option<&BusinessStatus>;
+    fn types(&self) -> &[PlaceTypes];
+    fn price_level(&self) -> Option<&PriceLevel>;
+    /// The distance from an origin, only known for a [`DistancedPlace`].
+    fn distance_meters(&self) -> Option<f64>;
+}
+
+impl FilterablePlace for PlaceSearchPlace {
+    fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    fn rating(&self) -> Option<f32> {
+        self.rating
+    }
+
+    fn user_ratings_total(&self) -> Option<i32> {
+        self.user_ratings_total
+    }
+
+    fn open_now(&self) -> Option<bool> {
+        self.opening_hours.as_ref()?.open_now
+    }
+
+    fn business_status(&self) -> Option<&BusinessStatus> {
+        self.business_status.as_ref()
+    }
+
+    fn types(&self) -> &[PlaceTypes] {
+        self.types.as_deref().unwrap_or_default()
+    }
+
+    fn price_level(&self) -> Option<&PriceLevel> {
+        self.price_level.as_ref()
+    }
+
+    fn distance_meters(&self) -> Option<f64> {
+        None
+    }
+}
+
+impl FilterablePlace for PlaceDetailsPlace {
+    fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    fn rating(&self) -> Option<f32> {
+        self.rating
+    }
+
+    fn user_ratings_total(&self) -> Option<i32> {
+        self.user_ratings_total
+    }
+
+    fn open_now(&self) -> Option<bool> {
+        self.current_opening_hours
+            .as_ref()
+            .and_then(|hours| hours.open_now)
+            .or_else(|| self.opening_hours.as_ref()?.open_now)
+    }
+
+    fn business_status(&self) -> Option<&BusinessStatus> {
+        self.business_status.as_ref()
+    }
+
+    fn types(&self) -> &[PlaceTypes] {
+        self.types.as_deref().unwrap_or_default()
+    }
+
+    fn price_level(&self) -> Option<&PriceLevel> {
+        self.price_level.as_ref()
+    }
+
+    fn distance_meters(&self) -> Option<f64> {
+        None
+    }
+}
+
+impl<T: FilterablePlace> FilterablePlace for &T {
+    fn name(&self) -> Option<&str> {
+        (*self).name()
+    }
+
+    fn rating(&self) -> Option<f32> {
+        (*self).rating()
+    }
+
+    fn user_ratings_total(&self) -> Option<i32> {
+        (*self).user_ratings_total()
+    }
+
+    fn open_now(&self) -> Option<bool> {
+        (*self).open_now()
+    }
+
+    fn business_status(&self) -> Option<&BusinessStatus> {
+        (*self).business_status()
+    }
+
+    fn types(&self) -> &[PlaceTypes] {
+        (*self).types()
+    }
+
+    fn price_level(&self) -> Option<&PriceLevel> {
+        (*self).price_level()
+    }
+
+    fn distance_meters(&self) -> Option<f64> {
+        (*self).distance_meters()
+    }
+}
+
+impl FilterablePlace for DistancedPlace<'_> {
+    fn name(&self) -> Option<&str> {
+        self.place.name()
+    }
+
+    fn rating(&self) -> Option<f32> {
+        self.place.rating()
+    }
+
+    fn user_ratings_total(&self) -> Option<i32> {
+        self.place.user_ratings_total()
+    }
+
+    fn open_now(&self) -> Option<bool> {
+        self.place.open_now()
+    }
+
+    fn business_status(&self) -> Option<&BusinessStatus> {
+        self.place.business_status()
+    }
+
+    fn types(&self) -> &[PlaceTypes] {
+        self.place.types()
+    }
+
+    fn price_level(&self) -> Option<&PriceLevel> {
+        self.place.price_level()
+    }
+
+    fn distance_meters(&self) -> Option<f64> {
+        self.distance_meters
+    }
+}
+
+/// A set of conditions a place must all meet. Places missing a filtered field never match.
+#[derive(Debug, Clone, Default)]
+pub struct PlaceFilter {
+    min_rating: Option<f32>,
+    min_user_ratings_total: Option<i32>,
+    open_now: bool,
+    business_statuses: Vec<BusinessStatus>,
+    types: Vec<PlaceTypes>,
+    price_range: Option<(u8, u8)>,
+    name: Option<Regex>,
+}
+
+impl PlaceFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_min_rating(&mut self, min_rating: f32) -> &mut PlaceFilter {
+        self.min_rating = Some(min_rating);
+        self
+    }
+
+    pub fn with_min_user_ratings_total(&mut self, min_total: u32) -> &mut PlaceFilter {
+        self.min_user_ratings_total = Some(i32::try_from(min_total).unwrap_or(i32::MAX));
+        self
+    }
+
+    /// Keep only places reported as open now.
+    pub fn with_open_now(&mut self) -> &mut PlaceFilter {
+        self.open_now = true;
+        self
+    }
+
+    /// Accept a business status, may be called several times to accept any of them.
+    pub fn with_business_status(&mut self, business_status: BusinessStatus) -> &mut PlaceFilter {
+        self.business_statuses.push(business_status);
+        self
+    }
+
+    /// Keep places having at least one of the types, may be called several times to accept more.
+    pub fn with_any_type(&mut self, types: Vec<PlaceTypes>) -> &mut PlaceFilter {
+        self.types.extend(types);
+        self
+    }
+
+    /// Keep places whose price level is between `min` and `max`, inclusive.
+    ///
+    /// An unspecified or unknown bound leaves that side of the range open.
+    pub fn with_price_range(&mut self, min: PriceLevel, max: PriceLevel) -> &mut PlaceFilter {
+        self.price_range = Some((
+            min.level().unwrap_or(u8::MIN),
+            max.level().unwrap_or(u8::MAX),
+        ));
+        self
+    }
+
+    /// Keep places whose name matches a regular expression, e.g. `(?i)coffee`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the `regex::Error` of an invalid pattern.
+    pub fn with_name_matching(&mut self, pattern: &str) -> Result<&mut PlaceFilter, regex::Error> {
+        self.name = Some(Regex::new(pattern)?);
+        Ok(self)
+    }
+
+    pub fn matches(&self, place: &impl FilterablePlace) -> bool {
+        self.min_rating
+            .is_none_or(|min| place.rating().is_some_and(|rating| rating >= min))
+            && self
+                .min_user_ratings_total
+                .is_none_or(|min| place.user_ratings_total().is_some_and(|total| total >= min))
+            && (!self.open_now || place.open_now() == Some(true))
+            && (self.business_statuses.is_empty()
+                || place
+                    .business_status()
+                    .is_some_and(|status| self.business_statuses.contains(status)))
+            && (self.types.is_empty() || place.types().iter().any(|t| self.types.contains(t)))
+            && self.price_range.is_none_or(|(min, max)| {
+                place
+                    .price_level()
+                    .and_then(|price_level| price_level.level())
+                    .is_some_and(|level| (min..=max).contains(&level))
+            })
+            && self.name.as_ref().is_none_or(|name| {
+                place
+                    .name()
+                    .is_some_and(|place_name| name.is_match(place_name))
+            })
+    }
+
+    /// The places matching the filter.
+    pub fn filter<'p, P: FilterablePlace>(&self, places: &'p [P]) -> Vec<&'p P> {
+        places.iter().filter(|place| self.matches(*place)).collect()
+    }
+
+    /// Removes the places not matching the filter.
+    ///
+    /// Use `retain_matching` on a search result to also update its `total_results`.
+    pub fn retain<P: FilterablePlace>(&self, places: &mut Vec<P>) {
+        places.retain(|place| self.matches(place));
+    }
+}
+
+/// A field places can be sorted on.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SortKey {
+    Rating,
+    UserRatingsTotal,
+    PriceLevel,
+    Distance,
+    /// The name, ignoring case.
+    Name,
+    /// The rating pulled toward a prior for places with few reviews.
+    BayesianAverage(BayesianAverage),
+    /// The Wilson lower bound of the rating, with the z-score of the confidence.
+    WilsonLowerBound(f64),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SortOrder {
+    Ascending,
+    Descending,
+}
+
+/// An ordering on several keys, each key breaking the ties of the previous ones.
+/// Places missing a key are sorted last whatever the order.
+#[derive(Debug, Clone, Default)]
+pub struct PlaceSort {
+    keys: Vec<(SortKey, SortOrder)>,
+}
+
+impl PlaceSort {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn ascending(&mut self, key: SortKey) -> &mut PlaceSort {
+        self.keys.push((key, SortOrder::Ascending));
+        self
+    }
+
+    pub fn descending(&mut self, key: SortKey) -> &mut PlaceSort {
+        self.keys.push((key, SortOrder::Descending));
+        self
+    }
+
+    pub fn compare<P: FilterablePlace>(&self, a: &P, b: &P) -> Ordering {
+        self.keys
+            .iter()
+            .map(|(key, order)| match key {
+                SortKey::Name => missing_last(
+                    a.name().map(str::to_lowercase),
+                    b.name().map(str::to_lowercase),
+                    *order,
+                    |a, b| a.cmp(b),
+                ),
+                _ => missing_last(key.value(a), key.value(b), *order, f64::total_cmp),
+            })
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(Ordering::Equal)
+    }
+
+    /// Sorts the places, keeping the original order of equal places.
+    pub fn sort<P: FilterablePlace>(&self, places: &mut [P]) {
+        places.sort_by(|a, b| self.compare(a, b));
+    }
+}
+
+impl SortKey {
+    fn value(&self, place: &impl FilterablePlace) -> Option<f64> {
+        match self {
+            SortKey::Rating => place.rating().map(f64::from),
+            SortKey::UserRatingsTotal => place.user_ratings_total().map(f64::from),
+            SortKey::PriceLevel => place.price_level()?.level().map(f64::from),
+            SortKey::Distance => place.distance_meters(),
+            SortKey::Name => None,
+            SortKey::BayesianAverage(prior) => prior.score_place(place),
+            SortKey::WilsonLowerBound(z) => wilson_lower_bound_of(place, *z),
+        }
+    }
+}
+
+fn missing_last<T>(
+    a: Option<T>,
+    b: Option<T>,
+    order: SortOrder,
+    compare: impl Fn(&T, &T) -> Ordering,
+) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => match order {
+            SortOrder::Ascending => compare(&a, &b),
+            SortOrder::Descending => compare(&b, &a),
+        },
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PlaceFilter, PlaceSort, SortKey};
+    use crate::types::constants::{PlaceSearchPlace, PlaceTypes};
+    use crate::types::v1::{BusinessStatus, PriceLevel};
+
+    fn places() -> Vec<PlaceSearchPlace> {
+        serde_json::from_value(serde_json::json!([
+            {
+                "place_id": "roastery", "name": "The Roastery", "rating": 4.7,
+                "user_ratings_total": 2000, "price_level": 2, "business_status": "OPERATIONAL",
+                "types": ["cafe", "food"], "opening_hours": { "open_now": true }
+            },
+            {
+                "place_id": "new", "name": "New Coffee", "rating": 5.0,
+                "user_ratings_total": 3, "price_level": 1, "business_status": "OPERATIONAL",
+                "types": ["cafe"], "opening_hours": { "open_now": false }
+            },
+            {
+                "place_id": "bar", "name": "Corner Bar", "rating": 4.7,
+                "user_ratings_total": 150, "price_level": 3, "business_status": "CLOSED_TEMPORARILY",
+                "types": ["bar"]
+            },
+            { "place_id": "unrated", "name": "Unrated Coffee" }
+        ]))
+        .unwrap()
+    }
+
+    fn ids(places: &[&PlaceSearchPlace]) -> Vec<String> {
+        places.iter().map(|p| p.id.clone()).collect()
+    }
+
+    #[test]
+    fn test_filter() {
+        let places = places();
+        let mut filter = PlaceFilter::new();
+        filter
+            .with_min_rating(4.5)
+            .with_business_status(BusinessStatus::Operational)
+            .with_any_type(vec![PlaceTypes::Cafe, PlaceTypes::Bakery]);
+        assert_eq!(ids(&filter.filter(&places)), vec!["roastery", "new"]);
+
+        let mut cafes = filter.filter(&places);
+        PlaceSort::new()
+            .descending(SortKey::Rating)
+            .sort(&mut cafes);
+        assert_eq!(ids(&cafes), vec!["new", "roastery"]);
+
+        let mut by_type = PlaceFilter::new();
+        by_type
+            .with_any_type(vec![PlaceTypes::Cafe])
+            .with_any_type(vec![PlaceTypes::Bar]);
+        assert_eq!(
+            ids(&by_type.filter(&places)),
+            vec!["roastery", "new", "bar"]
+        );
+
+        filter.with_min_user_ratings_total(100).with_open_now();
+        assert_eq!(ids(&filter.filter(&places)), vec!["roastery"]);
+
+        let mut by_name = PlaceFilter::new();
+        by_name.with_name_matching("(?i)coffee$").unwrap();
+        assert_eq!(ids(&by_name.filter(&places)), vec!["new", "unrated"]);
+        assert!(PlaceFilter::new().with_name_matching("(").is_err());
+
+        let mut by_price = PlaceFilter::new();
+        by_price.with_price_range(PriceLevel::Moderate, PriceLevel::VeryExpensive);
+        let mut places = places;
+        by_price.retain(&mut places);
+        assert_eq!(
+            ids(&places.iter().collect::<Vec<_>>()),
+            vec!["roastery", "bar"]
+        );
+    }
+
+    #[test]
+    fn test_multi_key_sort() {
+        let mut places = places();
+        PlaceSort::new()
+            .descending(SortKey::Rating)
+            .descending(SortKey::UserRatingsTotal)
+            .sort(&mut places);
+        assert_eq!(
+            ids(&places.iter().collect::<Vec<_>>()),
+            vec!["new", "roastery", "bar", "unrated"]
+        );
+
+        PlaceSort::new()
+            .ascending(SortKey::PriceLevel)
+            .ascending(SortKey::Name)
+            .sort(&mut places);
+        assert_eq!(
+            ids(&places.iter().collect::<Vec<_>>()),
+            vec!["new", "roastery", "bar", "unrated"]
+        );
+
+        PlaceSort::new().ascending(SortKey::Name).sort(&mut places);
+        assert_eq!(
+            ids(&places.iter().collect::<Vec<_>>()),
+            vec!["bar", "new", "roastery", "unrated"]
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use crate::types::attribution::HtmlAttribution;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -9,3 +10,33 @@ pub struct Photo {
     pub width: u32,
     pub html_attributions: Vec<String>,
 }
+
+/// The URL a photo reference redirects to, with the attributions to display next to it.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct PhotoUrl {
+    pub url: String,
+    pub html_attributions: Vec<String>,
+}
+
+/// The manifest written next to the photos downloaded by `PlacePhotoArchive`.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct PhotoManifest {
+    pub place_id: String,
+    pub photos: Vec<PhotoManifestEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct PhotoManifestEntry {
+    /// The file name of the photo, relative to the manifest, `None` if it failed.
+    pub file: Option<String>,
+    pub photo_reference: String,
+    /// The width of the original photo.
+    pub width: u32,
+    /// The height of the original photo.
+    pub height: u32,
+    pub content_type: Option<String>,
+    pub bytes: u64,
+    pub html_attributions: Vec<HtmlAttribution>,
+    /// Why the photo could not be downloaded.
+    pub error: Option<String>,
+}
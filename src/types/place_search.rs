@@ -1,5 +1,8 @@
+use crate::types::constants::place::Location;
 use crate::types::constants::PlaceSearchPlace;
+use crate::types::place_filter::PlaceFilter;
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 use strum_macros::{Display, EnumString};
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
@@ -107,6 +110,152 @@ impl TextSearchResult {
     }
 }
 
+impl PlaceSearchPlace {
+    pub fn location(&self) -> Option<&Location> {
+        self.geometry.as_ref()?.location.as_ref()
+    }
+
+    /// The distance in meters from a location, `None` if the place has no location.
+    pub fn distance_from(&self, origin: &Location) -> Option<f64> {
+        origin.distance_to(self.location()?)
+    }
+}
+
+/// A search result place with its distance and bearing from an origin.
+#[derive(Debug, Clone, Copy)]
+pub struct DistancedPlace<'a> {
+    pub place: &'a PlaceSearchPlace,
+    /// The distance in meters from the origin, `None` if the place has no location.
+    pub distance_meters: Option<f64>,
+    /// The initial bearing in degrees from the origin, `None` if the place has no location.
+    pub bearing: Option<f64>,
+}
+
+impl<'a> DistancedPlace<'a> {
+    pub fn new(place: &'a PlaceSearchPlace, origin: &Location) -> Self {
+        DistancedPlace {
+            place,
+            distance_meters: place.distance_from(origin),
+            bearing: place.location().and_then(|l| origin.bearing_to(l)),
+        }
+    }
+}
+
+fn distanced_places<'a>(
+    places: &'a [PlaceSearchPlace],
+    origin: &Location,
+) -> Vec<DistancedPlace<'a>> {
+    places
+        .iter()
+        .map(|place| DistancedPlace::new(place, origin))
+        .collect()
+}
+
+/// Sorts the closest places first, places without a location last.
+fn sort_by_distance(places: &mut Vec<PlaceSearchPlace>, origin: &Location) {
+    let mut distanced: Vec<(Option<f64>, PlaceSearchPlace)> = places
+        .drain(..)
+        .map(|place| (place.distance_from(origin), place))
+        .collect();
+    distanced.sort_by(|(a, _), (b, _)| match (a, b) {
+        (Some(a), Some(b)) => a.total_cmp(b),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    });
+    places.extend(distanced.into_iter().map(|(_, place)| place));
+}
+
+/// Keeps the places within `max_distance_meters`, dropping places without a location.
+fn retain_within(places: &mut Vec<PlaceSearchPlace>, origin: &Location, max_distance_meters: f64) {
+    places.retain(|place| {
+        place
+            .distance_from(origin)
+            .is_some_and(|distance| distance <= max_distance_meters)
+    });
+}
+
+impl NearbySearchResult {
+    /// The places with their distance and bearing from an origin, in the result order.
+    pub fn distanced_places(&self, origin: &Location) -> Vec<DistancedPlace<'_>> {
+        distanced_places(&self.places, origin)
+    }
+
+    /// Sorts the closest places first, places without a location last.
+    pub fn sort_by_distance(&mut self, origin: &Location) -> &mut Self {
+        sort_by_distance(&mut self.places, origin);
+        self
+    }
+
+    /// Keeps the places within `max_distance_meters` of the origin, updating `total_results`.
+    pub fn retain_within(&mut self, origin: &Location, max_distance_meters: f64) -> &mut Self {
+        retain_within(&mut self.places, origin, max_distance_meters);
+        self.calculate_total_results();
+        self
+    }
+
+    /// Keeps the places matching a filter, updating `total_results`.
+    pub fn retain_matching(&mut self, filter: &PlaceFilter) -> &mut Self {
+        filter.retain(&mut self.places);
+        self.calculate_total_results();
+        self
+    }
+}
+
+impl FindPlaceSearchResult {
+    /// The places with their distance and bearing from an origin, in the result order.
+    pub fn distanced_places(&self, origin: &Location) -> Vec<DistancedPlace<'_>> {
+        distanced_places(&self.places, origin)
+    }
+
+    /// Sorts the closest places first, places without a location last.
+    pub fn sort_by_distance(&mut self, origin: &Location) -> &mut Self {
+        sort_by_distance(&mut self.places, origin);
+        self
+    }
+
+    /// Keeps the places within `max_distance_meters` of the origin, updating `total_results`.
+    pub fn retain_within(&mut self, origin: &Location, max_distance_meters: f64) -> &mut Self {
+        retain_within(&mut self.places, origin, max_distance_meters);
+        self.calculate_total_results();
+        self
+    }
+
+    /// Keeps the places matching a filter, updating `total_results`.
+    pub fn retain_matching(&mut self, filter: &PlaceFilter) -> &mut Self {
+        filter.retain(&mut self.places);
+        self.calculate_total_results();
+        self
+    }
+}
+
+impl TextSearchResult {
+    /// The places with their distance and bearing from an origin, in the result order.
+    pub fn distanced_places(&self, origin: &Location) -> Vec<DistancedPlace<'_>> {
+        distanced_places(&self.places, origin)
+    }
+
+    /// Sorts the closest places first, places without a location last.
+    pub fn sort_by_distance(&mut self, origin: &Location) -> &mut Self {
+        sort_by_distance(&mut self.places, origin);
+        self
+    }
+
+    /// Keeps the places within `max_distance_meters` of the origin, updating `total_results`.
+    pub fn retain_within(&mut self, origin: &Location, max_distance_meters: f64) -> &mut Self {
+        retain_within(&mut self.places, origin, max_distance_meters);
+        self.calculate_total_results();
+        self
+    }
+
+    /// Keeps the places matching a filter, updating `total_results`.
+    pub fn retain_matching(&mut self, filter: &PlaceFilter) -> &mut Self {
+        filter.retain(&mut self.places);
+        self.calculate_total_results();
+        self
+    }
+}
+
 #[derive(Debug, Serialize, PartialEq, Eq, Deserialize, Display, EnumString, Clone, Default)]
 pub enum PlaceSearchStatus {
     #[serde(rename = "OK")]
@@ -129,3 +278,74 @@ pub enum PlaceSearchStatus {
     #[default]
     UnknownError,
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::types::constants::place::Location;
+    use crate::types::{PlaceFilter, PlaceSort, SortKey, TextSearchResult};
+
+    fn result() -> TextSearchResult {
+        serde_json::from_value(serde_json::json!({
+            "html_attributions": [],
+            "results": [
+                { "place_id": "far", "name": "Far Cafe", "geometry": { "location": { "lat": 47.40, "lng": 8.54 } } },
+                { "place_id": "unknown" },
+                { "place_id": "near", "name": "Near Bar", "geometry": { "location": { "lat": 47.38, "lng": 8.54 } } },
+                { "place_id": "east", "geometry": { "location": { "lat": 47.37, "lng": 8.56 } } }
+            ],
+            "status": "OK"
+        }))
+        .unwrap()
+    }
+
+    fn ids(result: &TextSearchResult) -> Vec<&str> {
+        result.places.iter().map(|p| p.id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_sort_by_distance() {
+        let mut result = result();
+        result.sort_by_distance(&Location::new(47.37, 8.54));
+
+        assert_eq!(ids(&result), vec!["near", "east", "far", "unknown"]);
+    }
+
+    #[test]
+    fn test_distanced_places() {
+        let result = result();
+        let places = result.distanced_places(&Location::new(47.37, 8.54));
+
+        let east = &places[3];
+        assert_eq!(east.place.id, "east");
+        assert!((east.distance_meters.unwrap() - 1_506.5).abs() < 1.0);
+        assert!((east.bearing.unwrap() - 90.0).abs() < 0.1);
+        assert_eq!(places[1].distance_meters, None);
+        assert_eq!(places[1].bearing, None);
+
+        let mut places = places;
+        PlaceSort::new()
+            .descending(SortKey::Distance)
+            .sort(&mut places);
+        let ids: Vec<&str> = places.iter().map(|p| p.place.id.as_str()).collect();
+        assert_eq!(ids, vec!["far", "east", "near", "unknown"]);
+    }
+
+    #[test]
+    fn test_retain_within() {
+        let mut result = result();
+        result.retain_within(&Location::new(47.37, 8.54), 2_000.0);
+
+        assert_eq!(ids(&result), vec!["near", "east"]);
+        assert_eq!(result.total_results, 2);
+    }
+
+    #[test]
+    fn test_retain_matching() {
+        let mut result = result();
+        result.calculate_total_results();
+        result.retain_matching(PlaceFilter::new().with_name_matching("Cafe$").unwrap());
+
+        assert_eq!(ids(&result), vec!["far"]);
+        assert_eq!(result.total_results, 1);
+    }
+}
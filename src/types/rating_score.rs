@@ -0,0 +1,151 @@
+use crate::types::place_filter::FilterablePlace;
+
+/// The z-score of a 95% confidence interval, a common choice for `wilson_lower_bound`.
+pub const WILSON_Z_95: f64 = 1.96;
+
+/// A Bayesian average, pulling the rating of places with few reviews toward a prior mean
+/// as if every place had `prior_weight` extra reviews of `prior_mean` stars.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct BayesianAverage {
+    pub prior_mean: f64,
+    pub prior_weight: f64,
+}
+
+impl BayesianAverage {
+    pub fn new(prior_mean: f64, prior_weight: f64) -> Self {
+        Self {
+            prior_mean,
+            prior_weight,
+        }
+    }
+
+    /// A prior whose mean is the average rating of all the reviews of the places, e.g.
+    /// of a whole search result. Returns `None` when none of the places is rated.
+    pub fn from_places<P: FilterablePlace>(places: &[P], prior_weight: f64) -> Option<Self> {
+        let (sum, count) = places
+            .iter()
+            .filter_map(rating_and_count)
+            .fold((0.0, 0.0), |(sum, count), (rating, total)| {
+                (sum + rating * total as f64, count + total as f64)
+            });
+        if count == 0.0 {
+            return None;
+        }
+        Some(Self::new(sum / count, prior_weight))
+    }
+
+    /// The weighted average of the rating and the prior, the plain `rating` when the
+    /// prior has no weight and the place no reviews.
+    pub fn score(&self, rating: f64, user_ratings_total: u32) -> f64 {
+        let count = user_ratings_total as f64;
+        let weight = self.prior_weight + count;
+        if weight == 0.0 {
+            return rating;
+        }
+        (self.prior_weight * self.prior_mean + count * rating) / weight
+    }
+
+    /// The score of a place, `None` if it has no rating.
+    pub fn score_place(&self, place: &impl FilterablePlace) -> Option<f64> {
+        let (rating, total) = rating_and_count(place)?;
+        Some(self.score(rating, total))
+    }
+}
+
+/// The lower bound of the Wilson score interval of a star rating, on the same 1 to 5 scale.
+///
+/// The average rating is approximated as a proportion of positive reviews, so that a 5.0
+/// with 3 reviews scores well below a 4.7 with 2,000. `z` is the z-score of the confidence,
+/// e.g. `WILSON_Z_95`.
+pub fn wilson_lower_bound(rating: f64, user_ratings_total: u32, z: f64) -> f64 {
+    if user_ratings_total == 0 {
+        return 1.0;
+    }
+    let n = user_ratings_total as f64;
+    let positive = ((rating - 1.0) / 4.0).clamp(0.0, 1.0);
+    let z2 = z * z;
+    let bound = (positive + z2 / (2.0 * n)
+        - z * ((positive * (1.0 - positive) + z2 / (4.0 * n)) / n).sqrt())
+        / (1.0 + z2 / n);
+    1.0 + 4.0 * bound
+}
+
+/// The Wilson lower bound of a place, `None` if it has no rating.
+pub fn wilson_lower_bound_of(place: &impl FilterablePlace, z: f64) -> Option<f64> {
+    let (rating, total) = rating_and_count(place)?;
+    Some(wilson_lower_bound(rating, total, z))
+}
+
+/// The rating of a place and its number of ratings, zero when unknown.
+fn rating_and_count(place: &impl FilterablePlace) -> Option<(f64, u32)> {
+    let rating = f64::from(place.rating()?);
+    let total = place
+        .user_ratings_total()
+        .and_then(|total| u32::try_from(total).ok())
+        .unwrap_or(0);
+    Some((rating, total))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{wilson_lower_bound, BayesianAverage, WILSON_Z_95};
+    use crate::types::constants::PlaceSearchPlace;
+    use crate::types::place_filter::{PlaceSort, SortKey};
+
+    fn places() -> Vec<PlaceSearchPlace> {
+        serde_json::from_value(serde_json::json!([
+            { "place_id": "new", "rating": 5.0, "user_ratings_total": 3 },
+            { "place_id": "popular", "rating": 4.7, "user_ratings_total": 2000 },
+            { "place_id": "average", "rating": 4.2, "user_ratings_total": 500 },
+            { "place_id": "unrated" }
+        ]))
+        .unwrap()
+    }
+
+    fn ids(places: &[PlaceSearchPlace]) -> Vec<&str> {
+        places.iter().map(|p| p.id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_bayesian_average() {
+        let prior = BayesianAverage::new(4.0, 10.0);
+        assert!((prior.score(5.0, 3) - 55.0 / 13.0).abs() < 1e-9);
+        assert!((prior.score(4.7, 2000) - 4.6965).abs() < 1e-3);
+        assert_eq!(prior.score(4.7, 0), 4.0);
+        assert_eq!(BayesianAverage::new(4.0, 0.0).score(4.7, 0), 4.7);
+
+        let places = places();
+        let prior = BayesianAverage::from_places(&places, 10.0).unwrap();
+        assert!((prior.prior_mean - (15.0 + 9400.0 + 2100.0) / 2503.0).abs() < 1e-4);
+        assert_eq!(prior.score_place(&places[3]), None);
+    }
+
+    #[test]
+    fn test_wilson_lower_bound() {
+        let new = wilson_lower_bound(5.0, 3, WILSON_Z_95);
+        let popular = wilson_lower_bound(4.7, 2000, WILSON_Z_95);
+        assert!(new < popular, "{} >= {}", new, popular);
+        assert!(popular < 4.7 && popular > 4.6);
+        assert_eq!(wilson_lower_bound(4.7, 0, WILSON_Z_95), 1.0);
+    }
+
+    #[test]
+    fn test_sort_by_score() {
+        let mut places = places();
+        PlaceSort::new()
+            .descending(SortKey::Rating)
+            .sort(&mut places);
+        assert_eq!(ids(&places), vec!["new", "popular", "average", "unrated"]);
+
+        let prior = BayesianAverage::from_places(&places, 50.0).unwrap();
+        PlaceSort::new()
+            .descending(SortKey::BayesianAverage(prior))
+            .sort(&mut places);
+        assert_eq!(ids(&places), vec!["popular", "new", "average", "unrated"]);
+
+        PlaceSort::new()
+            .descending(SortKey::WilsonLowerBound(WILSON_Z_95))
+            .sort(&mut places);
+        assert_eq!(ids(&places), vec!["popular", "average", "new", "unrated"]);
+    }
+}
@@ -0,0 +1,136 @@
+use crate::types::v1::ApiError;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct AutocompleteResult {
+    #[serde(default)]
+    pub suggestions: Vec<Suggestion>,
+    pub error: Option<ApiError>,
+}
+
+impl AutocompleteResult {
+    /// Returns an iterator over the place predictions of the suggestions.
+    pub fn place_predictions(&self) -> impl Iterator<Item = &PlacePrediction> {
+        self.suggestions
+            .iter()
+            .filter_map(|s| s.place_prediction.as_ref())
+    }
+
+    /// Returns an iterator over the query predictions of the suggestions.
+    pub fn query_predictions(&self) -> impl Iterator<Item = &QueryPrediction> {
+        self.suggestions
+            .iter()
+            .filter_map(|s| s.query_prediction.as_ref())
+    }
+
+    pub fn display(&self) -> String {
+        let suggestions = self
+            .suggestions
+            .iter()
+            .map(|s| s.text().unwrap_or_default().to_string())
+            .collect::<Vec<String>>()
+            .join(", ");
+        format!(
+            "AutocompleteResult {{ suggestions: [{}], error: {} }}",
+            suggestions,
+            self.error.as_ref().map(|e| e.display()).unwrap_or_default()
+        )
+    }
+}
+
+/// A suggestion holds either a place prediction or a query prediction.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Suggestion {
+    pub place_prediction: Option<PlacePrediction>,
+    pub query_prediction: Option<QueryPrediction>,
+}
+
+impl Suggestion {
+    /// The full text of the prediction.
+    pub fn text(&self) -> Option<&str> {
+        match (&self.place_prediction, &self.query_prediction) {
+            (Some(place), _) => place.text.as_ref().map(|t| t.text.as_str()),
+            (_, Some(query)) => query.text.as_ref().map(|t| t.text.as_str()),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PlacePrediction {
+    /// The resource name of the place, `places/{place_id}`.
+    pub place: Option<String>,
+    pub place_id: Option<String>,
+    pub text: Option<FormattableText>,
+    pub structured_format: Option<StructuredFormat>,
+    pub types: Option<Vec<String>>,
+    /// Distance from the `origin` of the request, only set when an origin was provided.
+    pub distance_meters: Option<u32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryPrediction {
+    pub text: Option<FormattableText>,
+    pub structured_format: Option<StructuredFormat>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct StructuredFormat {
+    pub main_text: Option<FormattableText>,
+    pub secondary_text: Option<FormattableText>,
+}
+
+/// A text with the ranges matching the input highlighted.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct FormattableText {
+    pub text: String,
+    pub matches: Option<Vec<StringRange>>,
+}
+
+/// A range of characters in a text, the start offset is omitted by the API when 0.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct StringRange {
+    #[serde(default)]
+    pub start_offset: u32,
+    pub end_offset: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AutocompleteResult;
+
+    #[test]
+    fn test_autocomplete_deserialize() {
+        let result: AutocompleteResult = serde_json::from_str(
+            r#"{
+                "suggestions": [
+                    {
+                        "placePrediction": {
+                            "place": "places/ChIJ5YQQf1GHhYARPKG7WLIaOko",
+                            "placeId": "ChIJ5YQQf1GHhYARPKG7WLIaOko",
+                            "text": { "text": "Amoeba Music, Haight Street, San Francisco, CA, USA", "matches": [{ "endOffset": 6 }] },
+                            "types": ["store", "establishment"],
+                            "distanceMeters": 3012
+                        }
+                    },
+                    { "queryPrediction": { "text": { "text": "Amoeba Music near me" } } }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let place = result.place_predictions().next().unwrap();
+        assert_eq!(place.distance_meters, Some(3012));
+        assert_eq!(
+            place.text.as_ref().unwrap().matches.as_ref().unwrap()[0].start_offset,
+            0
+        );
+        assert_eq!(result.query_predictions().count(), 1);
+        assert_eq!(result.suggestions[1].text(), Some("Amoeba Music near me"));
+    }
+}
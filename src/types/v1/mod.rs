@@ -0,0 +1,24 @@
+pub mod autocomplete;
+pub mod opening_hours;
+pub mod photo;
+pub mod place;
+pub mod place_details;
+pub mod place_fields;
+pub mod search;
+
+pub use autocomplete::{
+    AutocompleteResult, FormattableText, PlacePrediction, QueryPrediction, StringRange,
+    StructuredFormat, Suggestion,
+};
+pub use opening_hours::{Date, OpeningHours, Period, Point, SpecialDay};
+pub use photo::{AuthorAttribution, Photo, PhotoMedia};
+pub use place::{
+    AccessibilityOptions, AddressComponent, Attribution, BusinessStatus, IncompleteLocation,
+    LatLng, LocalizedText, ParkingOptions, PaymentOptions, Place, PlusCode, PriceLevel, Review,
+    Viewport,
+};
+pub use place_details::GetPlaceResult;
+pub use place_fields::PlaceFields;
+pub use search::{
+    ApiError, Circle, LocationArea, RankPreference, Rectangle, SearchNearbyResult, SearchTextResult,
+};
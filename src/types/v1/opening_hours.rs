@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct OpeningHours {
+    pub open_now: Option<bool>,
+    pub periods: Option<Vec<Period>>,
+    pub weekday_descriptions: Option<Vec<String>>,
+    pub secondary_hours_type: Option<String>,
+    pub special_days: Option<Vec<SpecialDay>>,
+    pub next_open_time: Option<String>,
+    pub next_close_time: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct Period {
+    pub open: Option<Point>,
+    pub close: Option<Point>,
+}
+
+/// A point in the week at which a place opens or closes.
+///
+/// `day` is 0 for Sunday through 6 for Saturday.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct Point {
+    pub day: Option<i32>,
+    pub hour: Option<i32>,
+    pub minute: Option<i32>,
+    pub date: Option<Date>,
+    pub truncated: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct SpecialDay {
+    pub date: Option<Date>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct Date {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+}
@@ -0,0 +1,38 @@
+use crate::types::v1::ApiError;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Photo {
+    /// The resource name of the photo, `places/{place_id}/photos/{photo}`.
+    pub name: String,
+    pub width_px: Option<u32>,
+    pub height_px: Option<u32>,
+    pub author_attributions: Option<Vec<AuthorAttribution>>,
+    pub flag_content_uri: Option<String>,
+    pub google_maps_uri: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthorAttribution {
+    pub display_name: Option<String>,
+    pub uri: Option<String>,
+    pub photo_uri: Option<String>,
+}
+
+/// The media of a photo, fetched through `places/{place_id}/photos/{photo}/media`.
+///
+/// Either `photo_uri` (when the redirect was skipped) or `bytes` (when the redirect
+/// was followed) is set on success.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PhotoMedia {
+    pub name: Option<String>,
+    pub photo_uri: Option<String>,
+    #[serde(skip)]
+    pub bytes: Option<Vec<u8>>,
+    #[serde(default)]
+    pub author_attributions: Vec<AuthorAttribution>,
+    pub error: Option<ApiError>,
+}
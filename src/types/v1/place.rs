@@ -0,0 +1,409 @@
+use crate::types::constants::place::{remove_empty_fields, Location};
+use crate::types::constants::PlaceTypes;
+use crate::types::v1::{AuthorAttribution, OpeningHours, Photo};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{json, Value};
+use std::fmt;
+use strum_macros::{Display, EnumString};
+
+/// A place as returned by the Places API (New).
+///
+/// Only the fields requested through the field mask are populated, every
+/// other field is left as `None`.
+#[derive(Debug, Serialize, Deserialize, Default, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Place {
+    // Basic
+    pub name: Option<String>,
+    pub id: Option<String>,
+    pub display_name: Option<LocalizedText>,
+    pub types: Option<Vec<PlaceTypes>>,
+    pub primary_type: Option<String>,
+    pub primary_type_display_name: Option<LocalizedText>,
+    pub formatted_address: Option<String>,
+    pub short_formatted_address: Option<String>,
+    pub address_components: Option<Vec<AddressComponent>>,
+    pub adr_format_address: Option<String>,
+    pub location: Option<LatLng>,
+    pub viewport: Option<Viewport>,
+    pub plus_code: Option<PlusCode>,
+    pub google_maps_uri: Option<String>,
+    pub business_status: Option<BusinessStatus>,
+    pub utc_offset_minutes: Option<i32>,
+    pub icon_mask_base_uri: Option<String>,
+    pub icon_background_color: Option<String>,
+    pub photos: Option<Vec<Photo>>,
+    pub accessibility_options: Option<AccessibilityOptions>,
+    pub attributions: Option<Vec<Attribution>>,
+
+    // Contact
+    pub national_phone_number: Option<String>,
+    pub international_phone_number: Option<String>,
+    pub website_uri: Option<String>,
+    pub regular_opening_hours: Option<OpeningHours>,
+    pub current_opening_hours: Option<OpeningHours>,
+    pub regular_secondary_opening_hours: Option<Vec<OpeningHours>>,
+    pub current_secondary_opening_hours: Option<Vec<OpeningHours>>,
+
+    // Atmosphere
+    pub price_level: Option<PriceLevel>,
+    pub rating: Option<f32>,
+    pub user_rating_count: Option<i32>,
+    pub reviews: Option<Vec<Review>>,
+    pub editorial_summary: Option<LocalizedText>,
+    pub payment_options: Option<PaymentOptions>,
+    pub parking_options: Option<ParkingOptions>,
+    pub allows_dogs: Option<bool>,
+    pub curbside_pickup: Option<bool>,
+    pub delivery: Option<bool>,
+    pub dine_in: Option<bool>,
+    pub good_for_children: Option<bool>,
+    pub good_for_groups: Option<bool>,
+    pub good_for_watching_sports: Option<bool>,
+    pub live_music: Option<bool>,
+    pub menu_for_children: Option<bool>,
+    pub outdoor_seating: Option<bool>,
+    pub reservable: Option<bool>,
+    pub restroom: Option<bool>,
+    pub serves_beer: Option<bool>,
+    pub serves_breakfast: Option<bool>,
+    pub serves_brunch: Option<bool>,
+    pub serves_cocktails: Option<bool>,
+    pub serves_coffee: Option<bool>,
+    pub serves_dessert: Option<bool>,
+    pub serves_dinner: Option<bool>,
+    pub serves_lunch: Option<bool>,
+    pub serves_vegetarian_food: Option<bool>,
+    pub serves_wine: Option<bool>,
+    pub takeout: Option<bool>,
+}
+
+impl Place {
+    pub fn display(&self) -> String {
+        let json_value: Value = json!(self);
+        let cleaned_value = remove_empty_fields(&json_value);
+        serde_json::to_string_pretty(&cleaned_value)
+            .unwrap_or_else(|_| String::from("Error formatting Place"))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LocalizedText {
+    pub text: Option<String>,
+    pub language_code: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq)]
+pub struct LatLng {
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+impl LatLng {
+    pub fn new(latitude: f64, longitude: f64) -> Self {
+        LatLng {
+            latitude,
+            longitude,
+        }
+    }
+}
+
+impl TryFrom<Location> for LatLng {
+    type Error = IncompleteLocation;
+
+    fn try_from(location: Location) -> Result<Self, Self::Error> {
+        match (location.lat, location.lon) {
+            (Some(lat), Some(lon)) => Ok(LatLng::new(lat, lon)),
+            _ => Err(IncompleteLocation),
+        }
+    }
+}
+
+/// A `Location` missing its latitude or longitude cannot be converted to a `LatLng`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct IncompleteLocation;
+
+impl fmt::Display for IncompleteLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "location is missing its latitude or longitude")
+    }
+}
+
+impl std::error::Error for IncompleteLocation {}
+
+impl From<LatLng> for Location {
+    fn from(lat_lng: LatLng) -> Self {
+        Location::new(lat_lng.latitude, lat_lng.longitude)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq)]
+pub struct Viewport {
+    pub low: LatLng,
+    pub high: LatLng,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PlusCode {
+    pub global_code: Option<String>,
+    pub compound_code: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressComponent {
+    pub long_text: Option<String>,
+    pub short_text: Option<String>,
+    pub types: Option<Vec<PlaceTypes>>,
+    pub language_code: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Review {
+    pub name: Option<String>,
+    pub relative_publish_time_description: Option<String>,
+    pub rating: Option<f32>,
+    pub text: Option<LocalizedText>,
+    pub original_text: Option<LocalizedText>,
+    pub author_attribution: Option<AuthorAttribution>,
+    pub publish_time: Option<String>,
+    pub flag_content_uri: Option<String>,
+    pub google_maps_uri: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Attribution {
+    pub provider: Option<String>,
+    pub provider_uri: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessibilityOptions {
+    pub wheelchair_accessible_parking: Option<bool>,
+    pub wheelchair_accessible_entrance: Option<bool>,
+    pub wheelchair_accessible_restroom: Option<bool>,
+    pub wheelchair_accessible_seating: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentOptions {
+    pub accepts_credit_cards: Option<bool>,
+    pub accepts_debit_cards: Option<bool>,
+    pub accepts_cash_only: Option<bool>,
+    pub accepts_nfc: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ParkingOptions {
+    pub free_parking_lot: Option<bool>,
+    pub paid_parking_lot: Option<bool>,
+    pub free_street_parking: Option<bool>,
+    pub paid_street_parking: Option<bool>,
+    pub valet_parking: Option<bool>,
+    pub free_garage_parking: Option<bool>,
+    pub paid_garage_parking: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Display, EnumString)]
+pub enum BusinessStatus {
+    #[strum(serialize = "BUSINESS_STATUS_UNSPECIFIED")]
+    Unspecified,
+    #[strum(serialize = "OPERATIONAL")]
+    Operational,
+    #[strum(serialize = "CLOSED_TEMPORARILY")]
+    ClosedTemporarily,
+    #[strum(serialize = "CLOSED_PERMANENTLY")]
+    ClosedPermanently,
+    /// A status added by Google after this release, kept as sent.
+    #[strum(default)]
+    Unknown(String),
+}
+
+impl Serialize for BusinessStatus {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for BusinessStatus {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(value.parse().unwrap_or(BusinessStatus::Unknown(value)))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Display, EnumString)]
+pub enum PriceLevel {
+    #[strum(serialize = "PRICE_LEVEL_UNSPECIFIED")]
+    Unspecified,
+    #[strum(serialize = "PRICE_LEVEL_FREE")]
+    Free,
+    #[strum(serialize = "PRICE_LEVEL_INEXPENSIVE")]
+    Inexpensive,
+    #[strum(serialize = "PRICE_LEVEL_MODERATE")]
+    Moderate,
+    #[strum(serialize = "PRICE_LEVEL_EXPENSIVE")]
+    Expensive,
+    #[strum(serialize = "PRICE_LEVEL_VERY_EXPENSIVE")]
+    VeryExpensive,
+    /// A price level added by Google after this release, kept as sent. An unknown legacy
+    /// level is kept as its decimal number, e.g. `"5"`.
+    #[strum(default)]
+    Unknown(String),
+}
+
+impl Serialize for PriceLevel {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PriceLevel {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(value.parse().unwrap_or(PriceLevel::Unknown(value)))
+    }
+}
+
+impl PriceLevel {
+    /// The price level of a legacy `price_level`, from 0 (free) to 4 (very expensive).
+    pub fn from_level(level: i64) -> Self {
+        match level {
+            0 => PriceLevel::Free,
+            1 => PriceLevel::Inexpensive,
+            2 => PriceLevel::Moderate,
+            3 => PriceLevel::Expensive,
+            4 => PriceLevel::VeryExpensive,
+            _ => PriceLevel::Unknown(level.to_string()),
+        }
+    }
+
+    /// The level from 0 (free) to 4 (very expensive), `None` if unspecified or unknown.
+    pub fn level(&self) -> Option<u8> {
+        match self {
+            PriceLevel::Free => Some(0),
+            PriceLevel::Inexpensive => Some(1),
+            PriceLevel::Moderate => Some(2),
+            PriceLevel::Expensive => Some(3),
+            PriceLevel::VeryExpensive => Some(4),
+            PriceLevel::Unspecified | PriceLevel::Unknown(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BusinessStatus, IncompleteLocation, LatLng, Place, PriceLevel};
+    use crate::types::constants::place::Location;
+    use crate::types::constants::PlaceTypes;
+
+    #[test]
+    fn test_place_deserialize() {
+        let place: Place = serde_json::from_str(
+            r#"{
+                "id": "ChIJj61dQgK6j4AR4GeTYWZsKWw",
+                "displayName": { "text": "Blue Bottle Coffee", "languageCode": "en" },
+                "formattedAddress": "1 Ferry Building, San Francisco, CA 94111, USA",
+                "location": { "latitude": 37.7955, "longitude": -122.3937 },
+                "types": ["cafe", "coffee_shop"],
+                "businessStatus": "OPERATIONAL",
+                "priceLevel": "PRICE_LEVEL_MODERATE",
+                "rating": 4.5,
+                "userRatingCount": 1203
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(place.id.as_deref(), Some("ChIJj61dQgK6j4AR4GeTYWZsKWw"));
+        assert_eq!(
+            place.display_name.and_then(|n| n.text).as_deref(),
+            Some("Blue Bottle Coffee")
+        );
+        assert_eq!(place.location, Some(LatLng::new(37.7955, -122.3937)));
+        assert_eq!(
+            place.types,
+            Some(vec![
+                PlaceTypes::Cafe,
+                PlaceTypes::Unknown(String::from("coffee_shop"))
+            ])
+        );
+        assert_eq!(place.business_status, Some(BusinessStatus::Operational));
+        assert_eq!(place.price_level, Some(PriceLevel::Moderate));
+        assert_eq!(place.user_rating_count, Some(1203));
+    }
+
+    #[test]
+    fn test_place_nested_deserialize() {
+        let place: Place = serde_json::from_str(
+            r#"{
+                "regularOpeningHours": {
+                    "openNow": true,
+                    "periods": [{ "open": { "day": 1, "hour": 8, "minute": 30 }, "close": { "day": 1, "hour": 17, "minute": 0 } }],
+                    "weekdayDescriptions": ["Monday: 8:30 AM – 5:00 PM"]
+                },
+                "accessibilityOptions": { "wheelchairAccessibleEntrance": true },
+                "paymentOptions": { "acceptsCreditCards": true, "acceptsNfc": false },
+                "parkingOptions": { "freeStreetParking": true },
+                "photos": [{ "name": "places/abc/photos/def", "widthPx": 4032, "heightPx": 3024, "authorAttributions": [{ "displayName": "Jane" }] }]
+            }"#,
+        )
+        .unwrap();
+
+        let hours = place.regular_opening_hours.unwrap();
+        let period = &hours.periods.unwrap()[0];
+        assert_eq!(period.open.as_ref().unwrap().hour, Some(8));
+        assert_eq!(
+            place
+                .accessibility_options
+                .unwrap()
+                .wheelchair_accessible_entrance,
+            Some(true)
+        );
+        assert_eq!(place.payment_options.unwrap().accepts_nfc, Some(false));
+        assert_eq!(
+            place.parking_options.unwrap().free_street_parking,
+            Some(true)
+        );
+        assert_eq!(place.photos.unwrap()[0].width_px, Some(4032));
+    }
+
+    #[test]
+    fn test_lat_lng_from_location() {
+        let lat_lng = LatLng::try_from(Location::new(46.7749, 23.62));
+        assert_eq!(lat_lng, Ok(LatLng::new(46.7749, 23.62)));
+
+        let incomplete = Location {
+            lat: Some(46.7749),
+            lon: None,
+        };
+        assert_eq!(LatLng::try_from(incomplete), Err(IncompleteLocation));
+    }
+
+    #[test]
+    fn test_unknown_enum_values() {
+        let place: Place = serde_json::from_str(
+            r#"{ "businessStatus": "CLOSED_FOREVER", "priceLevel": "PRICE_LEVEL_PRICELESS" }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            place.business_status,
+            Some(BusinessStatus::Unknown(String::from("CLOSED_FOREVER")))
+        );
+        assert_eq!(
+            place.price_level,
+            Some(PriceLevel::Unknown(String::from("PRICE_LEVEL_PRICELESS")))
+        );
+
+        let json = serde_json::to_value(&place).unwrap();
+        assert_eq!(json["businessStatus"], "CLOSED_FOREVER");
+        assert_eq!(json["priceLevel"], "PRICE_LEVEL_PRICELESS");
+    }
+}
@@ -0,0 +1,19 @@
+use crate::types::v1::{ApiError, Place};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct GetPlaceResult {
+    #[serde(flatten)]
+    pub place: Place,
+    pub error: Option<ApiError>,
+}
+
+impl GetPlaceResult {
+    pub fn display(&self) -> String {
+        format!(
+            "GetPlaceResult {{ place: {}, error: {} }}",
+            self.place.display(),
+            self.error.as_ref().map(|e| e.display()).unwrap_or_default()
+        )
+    }
+}
@@ -0,0 +1,181 @@
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+
+/// Fields of a [`Place`](crate::types::v1::Place) that can be requested through
+/// the `X-Goog-FieldMask` header of the Places API (New).
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Hash, Eq, PartialEq, Display, EnumString)]
+pub enum PlaceFields {
+    // Basic
+    #[strum(serialize = "name")]
+    Name,
+    #[strum(serialize = "id")]
+    Id,
+    #[strum(serialize = "displayName")]
+    DisplayName,
+    #[strum(serialize = "types")]
+    Types,
+    #[strum(serialize = "primaryType")]
+    PrimaryType,
+    #[strum(serialize = "primaryTypeDisplayName")]
+    PrimaryTypeDisplayName,
+    #[strum(serialize = "formattedAddress")]
+    FormattedAddress,
+    #[strum(serialize = "shortFormattedAddress")]
+    ShortFormattedAddress,
+    #[strum(serialize = "addressComponents")]
+    AddressComponents,
+    #[strum(serialize = "adrFormatAddress")]
+    AdrFormatAddress,
+    #[strum(serialize = "location")]
+    Location,
+    #[strum(serialize = "viewport")]
+    Viewport,
+    #[strum(serialize = "plusCode")]
+    PlusCode,
+    #[strum(serialize = "googleMapsUri")]
+    GoogleMapsUri,
+    #[strum(serialize = "businessStatus")]
+    BusinessStatus,
+    #[strum(serialize = "utcOffsetMinutes")]
+    UtcOffsetMinutes,
+    #[strum(serialize = "iconMaskBaseUri")]
+    IconMaskBaseUri,
+    #[strum(serialize = "iconBackgroundColor")]
+    IconBackgroundColor,
+    #[strum(serialize = "photos")]
+    Photos,
+    #[strum(serialize = "accessibilityOptions")]
+    AccessibilityOptions,
+    #[strum(serialize = "attributions")]
+    Attributions,
+
+    // Contact
+    #[strum(serialize = "nationalPhoneNumber")]
+    NationalPhoneNumber,
+    #[strum(serialize = "internationalPhoneNumber")]
+    InternationalPhoneNumber,
+    #[strum(serialize = "websiteUri")]
+    WebsiteUri,
+    #[strum(serialize = "regularOpeningHours")]
+    RegularOpeningHours,
+    #[strum(serialize = "currentOpeningHours")]
+    CurrentOpeningHours,
+    #[strum(serialize = "regularSecondaryOpeningHours")]
+    RegularSecondaryOpeningHours,
+    #[strum(serialize = "currentSecondaryOpeningHours")]
+    CurrentSecondaryOpeningHours,
+
+    // Atmosphere
+    #[strum(serialize = "priceLevel")]
+    PriceLevel,
+    #[strum(serialize = "rating")]
+    Rating,
+    #[strum(serialize = "userRatingCount")]
+    UserRatingCount,
+    #[strum(serialize = "reviews")]
+    Reviews,
+    #[strum(serialize = "editorialSummary")]
+    EditorialSummary,
+    #[strum(serialize = "paymentOptions")]
+    PaymentOptions,
+    #[strum(serialize = "parkingOptions")]
+    ParkingOptions,
+    #[strum(serialize = "allowsDogs")]
+    AllowsDogs,
+    #[strum(serialize = "curbsidePickup")]
+    CurbsidePickup,
+    #[strum(serialize = "delivery")]
+    Delivery,
+    #[strum(serialize = "dineIn")]
+    DineIn,
+    #[strum(serialize = "goodForChildren")]
+    GoodForChildren,
+    #[strum(serialize = "goodForGroups")]
+    GoodForGroups,
+    #[strum(serialize = "goodForWatchingSports")]
+    GoodForWatchingSports,
+    #[strum(serialize = "liveMusic")]
+    LiveMusic,
+    #[strum(serialize = "menuForChildren")]
+    MenuForChildren,
+    #[strum(serialize = "outdoorSeating")]
+    OutdoorSeating,
+    #[strum(serialize = "reservable")]
+    Reservable,
+    #[strum(serialize = "restroom")]
+    Restroom,
+    #[strum(serialize = "servesBeer")]
+    ServesBeer,
+    #[strum(serialize = "servesBreakfast")]
+    ServesBreakfast,
+    #[strum(serialize = "servesBrunch")]
+    ServesBrunch,
+    #[strum(serialize = "servesCocktails")]
+    ServesCocktails,
+    #[strum(serialize = "servesCoffee")]
+    ServesCoffee,
+    #[strum(serialize = "servesDessert")]
+    ServesDessert,
+    #[strum(serialize = "servesDinner")]
+    ServesDinner,
+    #[strum(serialize = "servesLunch")]
+    ServesLunch,
+    #[strum(serialize = "servesVegetarianFood")]
+    ServesVegetarianFood,
+    #[strum(serialize = "servesWine")]
+    ServesWine,
+    #[strum(serialize = "takeout")]
+    Takeout,
+}
+
+impl PlaceFields {
+    /// Builds the value of an `X-Goog-FieldMask` header from a set of fields.
+    ///
+    /// # Arguments
+    ///
+    /// * `fields` - The fields to request.
+    /// * `prefix` - The path of the places in the response, e.g. `"places."`
+    ///   for search responses or `""` for a single place.
+    pub fn field_mask<'f, I>(fields: I, prefix: &str) -> String
+    where
+        I: IntoIterator<Item = &'f PlaceFields>,
+    {
+        let mut mask = fields
+            .into_iter()
+            .map(|f| format!("{}{}", prefix, f))
+            .collect::<Vec<_>>();
+        mask.sort();
+        mask.join(",")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PlaceFields;
+
+    #[test]
+    fn test_place_fields_as_str() {
+        assert_eq!(PlaceFields::DisplayName.to_string(), "displayName");
+        assert_eq!(PlaceFields::UserRatingCount.to_string(), "userRatingCount");
+        assert_eq!(
+            PlaceFields::RegularOpeningHours.to_string(),
+            "regularOpeningHours"
+        );
+    }
+
+    #[test]
+    fn test_place_fields_parse() {
+        let parsed_result: PlaceFields = "formattedAddress".parse().unwrap();
+        assert_eq!(parsed_result, PlaceFields::FormattedAddress);
+    }
+
+    #[test]
+    fn test_field_mask() {
+        let fields = [PlaceFields::Id, PlaceFields::DisplayName];
+        assert_eq!(
+            PlaceFields::field_mask(&fields, "places."),
+            "places.displayName,places.id"
+        );
+        assert_eq!(PlaceFields::field_mask(&fields, ""), "displayName,id");
+    }
+}
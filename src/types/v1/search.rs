@@ -0,0 +1,145 @@
+use crate::types::v1::{LatLng, Place};
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchTextResult {
+    #[serde(default)]
+    pub places: Vec<Place>,
+    pub next_page_token: Option<String>,
+    pub error: Option<ApiError>,
+}
+
+impl SearchTextResult {
+    pub fn display(&self) -> String {
+        let places = self
+            .places
+            .iter()
+            .map(|p| p.display())
+            .collect::<Vec<String>>()
+            .join(", ");
+        format!(
+            "SearchTextResult {{ places: [{}], next_page_token: {}, error: {} }}",
+            places,
+            self.next_page_token.as_deref().unwrap_or_default(),
+            self.error.as_ref().map(|e| e.display()).unwrap_or_default()
+        )
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct SearchNearbyResult {
+    #[serde(default)]
+    pub places: Vec<Place>,
+    pub error: Option<ApiError>,
+}
+
+impl SearchNearbyResult {
+    pub fn display(&self) -> String {
+        let places = self
+            .places
+            .iter()
+            .map(|p| p.display())
+            .collect::<Vec<String>>()
+            .join(", ");
+        format!(
+            "SearchNearbyResult {{ places: [{}], error: {} }}",
+            places,
+            self.error.as_ref().map(|e| e.display()).unwrap_or_default()
+        )
+    }
+}
+
+/// The error body returned by the Places API (New) for failed requests.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct ApiError {
+    pub code: Option<i32>,
+    pub message: Option<String>,
+    pub status: Option<String>,
+}
+
+impl ApiError {
+    pub fn display(&self) -> String {
+        format!(
+            "{} {}: {}",
+            self.code.unwrap_or_default(),
+            self.status.as_deref().unwrap_or_default(),
+            self.message.as_deref().unwrap_or_default()
+        )
+    }
+}
+
+/// An area used as `locationBias` or `locationRestriction` in a request body.
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum LocationArea {
+    Circle(Circle),
+    Rectangle(Rectangle),
+}
+
+impl LocationArea {
+    /// A circle of `radius` meters around `center`.
+    pub fn circle(center: LatLng, radius: f64) -> Self {
+        LocationArea::Circle(Circle { center, radius })
+    }
+
+    /// A rectangle spanning from the `low` (south-west) to the `high` (north-east) corner.
+    pub fn rectangle(low: LatLng, high: LatLng) -> Self {
+        LocationArea::Rectangle(Rectangle { low, high })
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq)]
+pub struct Circle {
+    pub center: LatLng,
+    pub radius: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq)]
+pub struct Rectangle {
+    pub low: LatLng,
+    pub high: LatLng,
+}
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, PartialEq, Display, EnumString)]
+pub enum RankPreference {
+    /// Only supported by `places:searchText`.
+    #[serde(rename = "RELEVANCE")]
+    #[strum(serialize = "RELEVANCE")]
+    Relevance,
+    #[serde(rename = "DISTANCE")]
+    #[strum(serialize = "DISTANCE")]
+    Distance,
+    /// Only supported by `places:searchNearby`.
+    #[serde(rename = "POPULARITY")]
+    #[strum(serialize = "POPULARITY")]
+    Popularity,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LocationArea, SearchTextResult};
+    use crate::types::v1::LatLng;
+    use serde_json::json;
+
+    #[test]
+    fn test_location_area_serialize() {
+        let area = LocationArea::circle(LatLng::new(46.7749, 23.62), 500.0);
+        assert_eq!(
+            json!(area),
+            json!({ "circle": { "center": { "latitude": 46.7749, "longitude": 23.62 }, "radius": 500.0 } })
+        );
+    }
+
+    #[test]
+    fn test_search_text_error_deserialize() {
+        let result: SearchTextResult = serde_json::from_str(
+            r#"{ "error": { "code": 403, "message": "Places API (New) has not been used", "status": "PERMISSION_DENIED" } }"#,
+        )
+        .unwrap();
+
+        assert!(result.places.is_empty());
+        assert_eq!(result.error.unwrap().code, Some(403));
+    }
+}